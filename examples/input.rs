@@ -1,7 +1,6 @@
 // noturses::examples::input
 
 use notcurses::*;
-use std::time::Instant;
 use std::{thread::sleep, time::Duration};
 
 fn main() -> NotcursesResult<()> {
@@ -27,9 +26,9 @@ fn main() -> NotcursesResult<()> {
         "-".repeat(50)
     )?;
 
-    let mut counting_time = Instant::now();
     loop {
-        let event = nc.poll_event()?;
+        // wait for an event, without spinning, doing other things in-between
+        let event = nc.poll_event_timeout(Duration::from_millis(100))?;
 
         if event.received() {
             putstrln![+render plane, "\n{event:?}"]?;
@@ -44,12 +43,8 @@ fn main() -> NotcursesResult<()> {
                 sleep(Duration::from_millis(250));
                 break;
             }
-        }
-
-        // do other things in-between
-        if counting_time.elapsed().as_millis() > 100 {
+        } else {
             putstr![+render plane, "."]?;
-            counting_time = Instant::now()
         }
     }
 
@@ -57,6 +57,12 @@ mod core_impls {
         }
     }
 
+    impl From<Rgb> for Channel {
+        fn from(rgb: Rgb) -> Channel {
+            Self::from_rgb(rgb)
+        }
+    }
+
     //
 
     /// Helper for `impl From<{int} | ({int}, …) | [{int; …}]> for Channel`.
@@ -0,0 +1,98 @@
+// notcurses::direct::builder
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    direct::Direct,
+    error::NotcursesResult as Result,
+    sys::{NcDirect, NcDirectFlag},
+};
+
+/// A [`Direct`] builder.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DirectBuilder {
+    flags: NcDirectFlag,
+}
+
+/// # constructors
+impl DirectBuilder {
+    /// Returns a new default `DirectBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a `Direct` instance.
+    pub fn build(self) -> Result<Direct> {
+        Direct::lock_direct()?;
+        match unsafe { NcDirect::with_flags(self.flags) } {
+            Ok(nc) => Ok(Direct {
+                nc,
+                channels: Channels::with_default(),
+            }),
+            Err(e) => {
+                Direct::unlock_direct();
+                Err(e.into())
+            }
+        }
+    }
+}
+
+/// # methods (chainable)
+impl DirectBuilder {
+    /// If `true`, input may be freely dropped.
+    ///
+    /// This ought be provided when the program does not intend to handle input.
+    ///
+    /// Default: *false*.
+    pub fn drain_input(mut self, drain: bool) -> Self {
+        self.set_flag(NcDirectFlag::DrainInput, drain);
+        self
+    }
+
+    /// If `true`, wont place the terminal into cbreak mode.
+    ///
+    /// This is needed for the input of [`read_line`] to be echoed.
+    ///
+    /// Default: *false*.
+    ///
+    /// [`read_line`]: Direct#method.read_line
+    pub fn inhibit_cbreak(mut self, inhibit: bool) -> Self {
+        self.set_flag(NcDirectFlag::InhibitCbreak, inhibit);
+        self
+    }
+
+    /// If `true`, wont call setlocale().
+    ///
+    /// Default: *false*.
+    pub fn inhibit_set_locale(mut self, inhibit: bool) -> Self {
+        self.set_flag(NcDirectFlag::InhibitSetLocale, inhibit);
+        self
+    }
+
+    /// If `true`, wont handle `SIGINT`, `SIGSEGV`, `SIGABRT` nor `SIGQUIT`.
+    ///
+    /// Default: *false*.
+    pub fn no_quit_sig_handlers(mut self, no_quit: bool) -> Self {
+        self.set_flag(NcDirectFlag::NoQuitSigHandlers, no_quit);
+        self
+    }
+
+    /// If `true`, will log warnings and errors to standard error.
+    ///
+    /// Default: *false*.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.set_flag(NcDirectFlag::Verbose, verbose);
+        self
+    }
+
+    // Sets or unsets a flag.
+    fn set_flag(&mut self, flag: NcDirectFlag, set: bool) {
+        if set {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+}
@@ -0,0 +1,492 @@
+// notcurses::direct::direct
+//
+//!
+//
+
+use super::DirectBuilder;
+use crate::{
+    color::{Channel, Channels},
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::Input,
+    plane::{Align, BoxChannels, BoxEdges, BoxStyle, Style},
+    sys::{
+        c_api::{self, ffi::wchar_t, libc},
+        NcChannels, NcDirect, NcError, NcInput, NcReceived, NcTime,
+    },
+    visual::Visual,
+    Position, Size, DIRECT_LOCK,
+};
+use core::ptr::null;
+use once_cell::sync::OnceCell;
+
+/// *Notcurses* direct mode, for styled line-oriented output without planes.
+///
+/// It writes directly to the terminal at the cursor position, without
+/// entering the alternate screen, and without rendering. This is useful for
+/// small utilities that only need colored output, boxes and inline images.
+///
+/// There can only be a single `Direct` instance per thread at any given moment,
+/// and not while there's a [`Notcurses`] instance.
+///
+/// [`Notcurses`]: crate::Notcurses
+///
+/// # Example
+/// ```ignore
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let mut direct = Direct::new()?;
+/// direct.set_fg(Rgb::new(0xAA, 0xFF, 0x22));
+/// direct.on_styles(Style::Bold)?;
+/// direct.putstrln("hello world")?;
+/// direct.set_styles(Style::None)?;
+/// direct.unset_fg();
+///
+/// let mut visual = Visual::from_file("image.png")?;
+/// direct.render_visual(&mut visual)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Direct {
+    pub(super) nc: *mut NcDirect,
+    pub(super) channels: Channels,
+}
+
+mod core_impls {
+    use super::Direct;
+    use core::fmt;
+
+    impl Drop for Direct {
+        fn drop(&mut self) {
+            let _ = self.into_ref_mut().flush();
+            unsafe { self.into_ref_mut().stop().expect("Direct.drop()") };
+            Direct::unlock_direct();
+        }
+    }
+
+    impl fmt::Debug for Direct {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Direct {{ {:?} }}", self.channels)
+        }
+    }
+}
+
+// private functions
+impl Direct {
+    // Errors if there's already one `Direct` or `Notcurses` instance in this thread.
+    // Activates the lock otherwise.
+    pub(super) fn lock_direct() -> Result<()> {
+        // both modes can't share the terminal.
+        if crate::Notcurses::is_initialized() {
+            return Error::msg(
+                "A `Direct` instance can't be initialized while a `Notcurses` instance exists.",
+            );
+        }
+        DIRECT_LOCK.with(|refcell| {
+            let cell = refcell.borrow_mut();
+            if cell.get().is_none() {
+                cell.set(true).unwrap();
+                Ok(())
+            } else {
+                Error::msg("Only one `Direct` instance is allowed per thread, at the same time.")
+            }
+        })
+    }
+
+    // Releases the lock, after dropping or failing to initialize an instance.
+    pub(super) fn unlock_direct() {
+        DIRECT_LOCK.with(|refcell| {
+            refcell.replace(OnceCell::new());
+        });
+    }
+
+    // Returns the event received with the provided deadline, or blocking if `None`.
+    fn get(&mut self, deadline: Option<&NcTime>) -> Result<Input> {
+        let deadline = deadline.map_or(null(), |d| d as *const NcTime);
+        let mut input = NcInput::new_empty();
+        let res = unsafe { c_api::ncdirect_get(self.nc, deadline, &mut input) };
+        if res == c_api::NCRESULT_ERR as u32 {
+            return Err(NcError::new_msg("Direct.get()").into());
+        }
+        Ok((NcReceived::from(res), input).into())
+    }
+}
+
+/// # constructors & deconstructors.
+impl Direct {
+    /// Returns a new `Direct` context.
+    pub fn new() -> Result<Self> {
+        DirectBuilder::new().build()
+    }
+
+    /// Returns a new `Direct` builder.
+    pub fn builder() -> DirectBuilder {
+        DirectBuilder::new()
+    }
+
+    /// Returns `true` if there's already a `Direct` instance initialized in this thread.
+    pub fn is_initialized() -> bool {
+        DIRECT_LOCK.with(|refcell| refcell.borrow().get().is_some())
+    }
+
+    /// Returns a shared reference to the inner [`NcDirect`].
+    pub fn into_ref(&self) -> &NcDirect {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcDirect`].
+    pub fn into_ref_mut(&mut self) -> &mut NcDirect {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # output
+impl Direct {
+    /// Writes a `string` at the cursor position, with the current colors and styles.
+    ///
+    /// The output is not flushed, see [`flush`][Direct#method.flush].
+    pub fn putstr(&mut self, string: &str) -> Result<()> {
+        let channels = NcChannels::from(self.channels);
+        Ok(self.into_ref_mut().putstr(channels, string)?)
+    }
+
+    /// Writes a `string` at the cursor position, followed by a newline.
+    ///
+    /// See [`putstr`][Direct#method.putstr].
+    pub fn putstrln(&mut self, string: &str) -> Result<()> {
+        self.putstr(string)?;
+        self.putln()
+    }
+
+    /// Writes a newline.
+    pub fn putln(&mut self) -> Result<()> {
+        // the colors are reset so that they don't bleed into the next line.
+        Ok(self.into_ref_mut().putstr(NcChannels::new(), "\n")?)
+    }
+
+    /// Writes a `string` at the cursor position, with the provided `channels`,
+    /// which are also kept as the current colors.
+    pub fn putstr_colored(&mut self, channels: impl Into<Channels>, string: &str) -> Result<()> {
+        self.set_channels(channels);
+        self.putstr(string)
+    }
+
+    /// Forces a flush of the output.
+    pub fn flush(&self) -> Result<()> {
+        Ok(self.into_ref().flush()?)
+    }
+
+    /// Clears the screen.
+    pub fn clear(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().clear()?)
+    }
+
+    /// Returns the size of the terminal.
+    pub fn size(&mut self) -> Size {
+        let (rows, cols) = self.into_ref_mut().dim_yx();
+        Size::new(cols as i32, rows as i32)
+    }
+}
+
+/// # colors & styles
+impl Direct {
+    /// Gets the current colors.
+    pub fn channels(&self) -> Channels {
+        self.channels
+    }
+
+    /// Sets the current colors.
+    pub fn set_channels(&mut self, channels: impl Into<Channels>) {
+        self.channels = channels.into();
+    }
+
+    /// Gets the foreground color.
+    pub fn fg(&self) -> Channel {
+        self.channels().fg()
+    }
+
+    /// Gets the background color.
+    pub fn bg(&self) -> Channel {
+        self.channels().bg()
+    }
+
+    /// Sets the foreground color.
+    ///
+    /// Returns the updated channels.
+    pub fn set_fg(&mut self, foreground: impl Into<Channel>) -> Channels {
+        self.channels.set_fg(foreground)
+    }
+
+    /// Sets the background color.
+    ///
+    /// Returns the updated channels.
+    pub fn set_bg(&mut self, background: impl Into<Channel>) -> Channels {
+        self.channels.set_bg(background)
+    }
+
+    /// Sets the default foreground color.
+    ///
+    /// Returns the updated channels.
+    pub fn unset_fg(&mut self) -> Channels {
+        self.set_fg(Channel::with_default())
+    }
+
+    /// Sets the default background color.
+    ///
+    /// Returns the updated channels.
+    pub fn unset_bg(&mut self) -> Channels {
+        self.set_bg(Channel::with_default())
+    }
+
+    /// Gets the current styles.
+    pub fn styles(&self) -> Style {
+        self.into_ref().styles().into()
+    }
+
+    /// Sets the current styles.
+    pub fn set_styles(&mut self, styles: impl Into<Style>) -> Result<()> {
+        Ok(self.into_ref_mut().styles_set(styles.into())?)
+    }
+
+    /// Adds the specified `styles`.
+    pub fn on_styles(&mut self, styles: impl Into<Style>) -> Result<()> {
+        Ok(self.into_ref_mut().styles_on(styles.into())?)
+    }
+
+    /// Deletes the specified `styles`.
+    pub fn off_styles(&mut self, styles: impl Into<Style>) -> Result<()> {
+        Ok(self.into_ref_mut().styles_off(styles.into())?)
+    }
+
+    /// Returns the styles supported by the terminal.
+    pub fn supported_styles(&self) -> Style {
+        self.into_ref().supported_styles().into()
+    }
+}
+
+/// # cursor
+impl Direct {
+    /// Returns the current cursor position.
+    ///
+    /// This requires querying the terminal, and can block.
+    pub fn cursor(&mut self) -> Result<Position> {
+        let (y, x) = self.into_ref_mut().cursor_yx()?;
+        Ok(Position::new(x as i32, y as i32))
+    }
+
+    /// Moves the cursor to the specified `position`.
+    pub fn cursor_move_to(&mut self, position: impl Into<Position>) -> Result<()> {
+        let (x, y): (u32, u32) = position.into().into();
+        Ok(self.into_ref_mut().cursor_set_yx(y, x)?)
+    }
+
+    /// Moves the cursor to the specified `row`, keeping the current column.
+    pub fn cursor_move_to_row(&mut self, row: u32) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_set_y(row)?)
+    }
+
+    /// Moves the cursor to the specified `column`, keeping the current row.
+    pub fn cursor_move_to_col(&mut self, column: u32) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_set_x(column)?)
+    }
+
+    /// Moves the cursor up by `rows`.
+    pub fn cursor_up(&mut self, rows: u32) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_up(rows as i32)?)
+    }
+
+    /// Moves the cursor down by `rows`.
+    pub fn cursor_down(&mut self, rows: u32) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_down(rows as i32)?)
+    }
+
+    /// Moves the cursor left by `columns`.
+    pub fn cursor_left(&mut self, columns: u32) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_left(columns as i32)?)
+    }
+
+    /// Moves the cursor right by `columns`.
+    pub fn cursor_right(&mut self, columns: u32) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_right(columns as i32)?)
+    }
+
+    /// Saves the cursor position, to be restored with [`cursor_pop`].
+    ///
+    /// [`cursor_pop`]: Direct#method.cursor_pop
+    pub fn cursor_push(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_push()?)
+    }
+
+    /// Restores the cursor position saved with [`cursor_push`].
+    ///
+    /// [`cursor_push`]: Direct#method.cursor_push
+    pub fn cursor_pop(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_pop()?)
+    }
+
+    /// Shows the cursor.
+    pub fn cursor_enable(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_enable()?)
+    }
+
+    /// Hides the cursor.
+    pub fn cursor_disable(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().cursor_disable()?)
+    }
+}
+
+/// # boxes & lines
+impl Direct {
+    /// Draws a box of the given `size` with its upper-left corner at the
+    /// cursor position, interpolating the colors of the corners along the edges.
+    ///
+    /// # Errors
+    /// - if the `size` is smaller than 2×2, or the box doesn't fit on the screen.
+    /// - if any grapheme cluster of the `box_style` is not a single `char`.
+    pub fn draw_box(
+        &mut self,
+        size: impl Into<Size>,
+        box_style: BoxStyle,
+        channels: impl Into<BoxChannels>,
+        edges: impl Into<BoxEdges>,
+    ) -> Result<()> {
+        let (w, h): (u32, u32) = size.into().into();
+        if w < 2 || h < 2 {
+            return Error::msg(&format!["A box can't be smaller than 2×2, found {w}×{h}."]);
+        }
+
+        // ul, ur, ll, lr, hline, vline
+        let mut wchars: [wchar_t; 6] = [0; 6];
+        for (i, egc) in box_style.egcs().iter().enumerate() {
+            let mut chars = egc.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => wchars[i] = c as wchar_t,
+                _ => return Error::msg(&format!["Invalid direct box character {egc:?}."]),
+            }
+        }
+
+        let channels = channels.into();
+        let [ul, ur, ll, lr] =
+            [channels.ul, channels.ur, channels.ll, channels.lr].map(|c| NcChannels::from(c).0);
+        let res = unsafe {
+            c_api::ncdirect_box(
+                self.nc,
+                ul,
+                ur,
+                ll,
+                lr,
+                wchars.as_ptr(),
+                h,
+                w,
+                edges.into().to_mask(),
+            )
+        };
+        if res < c_api::NCRESULT_OK {
+            return Error::msg(&format!["Couldn't draw a {w}×{h} direct box."]);
+        }
+        Ok(())
+    }
+
+    /// Draws a horizontal line of `len` columns from the cursor position,
+    /// using the `egc`, and interpolating the colors `from` and `to`.
+    pub fn hline(
+        &mut self,
+        egc: &str,
+        len: u32,
+        from: impl Into<Channels>,
+        to: impl Into<Channels>,
+    ) -> Result<()> {
+        let (from, to) = (NcChannels::from(from.into()), NcChannels::from(to.into()));
+        Ok(self.into_ref_mut().hline_interp(egc, len, from, to)?)
+    }
+
+    /// Draws a vertical line of `len` rows from the cursor position,
+    /// using the `egc`, and interpolating the colors `from` and `to`.
+    ///
+    /// The terminal will scroll as necessary.
+    pub fn vline(
+        &mut self,
+        egc: &str,
+        len: u32,
+        from: impl Into<Channels>,
+        to: impl Into<Channels>,
+    ) -> Result<()> {
+        let (from, to) = (NcChannels::from(from.into()), NcChannels::from(to.into()));
+        Ok(self.into_ref_mut().vline_interp(egc, len, from, to)?)
+    }
+}
+
+/// # visuals
+impl Direct {
+    /// Renders the `visual` inline, from the cursor position, with its
+    /// [`VisualOptions`][crate::VisualOptions].
+    ///
+    /// The image may span arbitrarily many rows, scrolling the terminal,
+    /// and is aligned horizontally using its horizontal alignment, if any.
+    pub fn render_visual(&mut self, visual: &mut Visual) -> Result<()> {
+        let options = visual.options();
+        let vo: crate::sys::NcVisualOptions = options.into();
+        let align = if vo.is_horaligned() {
+            Align::from(vo.x as u32)
+        } else {
+            Align::Left
+        };
+
+        let ncplane = unsafe { c_api::ffi::ncdirectf_render(self.nc, visual.into_ref_mut(), &vo) };
+        if ncplane.is_null() {
+            return Error::msg("Couldn't render the visual in direct mode.");
+        }
+        // this also destroys the plane.
+        let res = unsafe { c_api::ncdirect_raster_frame(self.nc, ncplane, align.into()) };
+        if res < c_api::NCRESULT_OK {
+            return Error::msg("Couldn't write the visual in direct mode.");
+        }
+        Ok(())
+    }
+
+    /// Renders the image file at `path` inline, from the cursor position,
+    /// with the default options.
+    pub fn render_image(&mut self, path: &str) -> Result<()> {
+        let mut visual = Visual::from_file(path)?;
+        self.render_visual(&mut visual)
+    }
+}
+
+/// # input
+impl Direct {
+    /// Reads a line of text, showing the `prompt`.
+    ///
+    /// For the input to be echoed, the `Direct` instance must be built with
+    /// [`inhibit_cbreak`][DirectBuilder#method.inhibit_cbreak].
+    pub fn read_line(&mut self, prompt: &str) -> Result<String> {
+        Ok(self.into_ref_mut().readline(prompt)?)
+    }
+
+    /// Waits for an event, blocking.
+    pub fn get_event(&mut self) -> Result<Input> {
+        self.get(None)
+    }
+
+    /// Tries to get an event, non blocking.
+    pub fn poll_event(&mut self) -> Result<Input> {
+        self.get(Some(&NcTime::new(0, 0)))
+    }
+
+    /// Waits for an event, blocking until the `timeout` expires.
+    ///
+    /// Returns an input with [`Received::NoInput`] if the `timeout` expired.
+    ///
+    /// [`Received::NoInput`]: crate::Received#variant.NoInput
+    pub fn poll_event_timeout(&mut self, timeout: core::time::Duration) -> Result<Input> {
+        // the C API expects an absolute deadline, against `CLOCK_MONOTONIC`.
+        let mut now = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) };
+        let deadline = core::time::Duration::new(now.tv_sec as u64, now.tv_nsec as u32) + timeout;
+        self.get(Some(&NcTime::new(
+            deadline.as_secs() as _,
+            deadline.subsec_nanos() as _,
+        )))
+    }
+}
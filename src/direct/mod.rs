@@ -0,0 +1,10 @@
+// notcurses::direct
+//
+//! Direct mode, for styled line-oriented output without planes.
+//
+
+mod builder;
+mod direct;
+
+pub use builder::DirectBuilder;
+pub use direct::Direct;
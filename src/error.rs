@@ -22,6 +22,9 @@ pub enum NotcursesError {
 
     /// An error message string.
     Message(String),
+
+    /// A feature not supported by the terminal.
+    Unsupported(String),
 }
 
 /// # Methods
@@ -42,6 +45,7 @@ mod core_impls {
                 NotcursesError::NcError(e) => e.fmt(f),
                 NotcursesError::IoError(e) => e.fmt(f),
                 NotcursesError::Message(string) => write!(f, "Message: {}", string),
+                NotcursesError::Unsupported(string) => write!(f, "Unsupported: {}", string),
             }
         }
     }
@@ -54,7 +58,13 @@ mod core_impls {
 }
 
 mod std_impls {
-    use super::NotcursesError;
+    use super::{IoError, NotcursesError};
 
     impl std::error::Error for NotcursesError {}
+
+    impl From<IoError> for NotcursesError {
+        fn from(e: IoError) -> Self {
+            Self::IoError(e)
+        }
+    }
 }
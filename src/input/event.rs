@@ -0,0 +1,378 @@
+// notcurses::input::event
+//
+//!
+//
+
+use crate::input::{Input, Key, KeyMod, Received};
+use core::time::Duration;
+use std::collections::VecDeque;
+
+/// An input event, including pasted text and focus changes.
+///
+/// Unlike [`Input`], it's not `Copy`, since it can own the pasted text.
+///
+/// The [`Paste`] and focus events are only recognized when enabled with
+/// [`NotcursesBuilder.bracketed_paste`] and [`NotcursesBuilder.focus_events`],
+/// and they're returned by [`Notcurses.get_extended_event`] and
+/// [`Notcurses.poll_extended_event`].
+///
+/// [`Paste`]: Event#variant.Paste
+/// [`NotcursesBuilder.bracketed_paste`]: crate::NotcursesBuilder#method.bracketed_paste
+/// [`NotcursesBuilder.focus_events`]: crate::NotcursesBuilder#method.focus_events
+/// [`Notcurses.get_extended_event`]: crate::Notcurses#method.get_extended_event
+/// [`Notcurses.poll_extended_event`]: crate::Notcurses#method.poll_extended_event
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// A key, character or mouse input.
+    Input(Input),
+
+    /// Text pasted into the terminal, all at once.
+    ///
+    /// Line breaks are received as `\n`.
+    Paste(String),
+
+    /// The terminal window gained the focus.
+    FocusGained,
+
+    /// The terminal window lost the focus.
+    FocusLost,
+}
+
+mod core_impls {
+    use super::{Event, Input};
+
+    impl From<Input> for Event {
+        fn from(input: Input) -> Event {
+            Event::Input(input)
+        }
+    }
+}
+
+/// # methods
+impl Event {
+    /// Returns the input, if it's an input event.
+    pub fn input(&self) -> Option<Input> {
+        match self {
+            Event::Input(input) => Some(*input),
+            _ => None,
+        }
+    }
+
+    /// Returns the pasted text, if it's a paste event.
+    pub fn paste(&self) -> Option<&str> {
+        match self {
+            Event::Paste(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if it's an input event that actually received some input.
+    pub fn received(&self) -> bool {
+        match self {
+            Event::Input(input) => input.received(),
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if it's a focus gained or lost event.
+    pub fn is_focus(&self) -> bool {
+        matches!(self, Event::FocusGained | Event::FocusLost)
+    }
+}
+
+// The escape sequences that delimit bracketed pastes, and report focus changes.
+const PASTE_START: &str = "\x1b[200~";
+const PASTE_END: &str = "\x1b[201~";
+const FOCUS_IN: &str = "\x1b[I";
+const FOCUS_OUT: &str = "\x1b[O";
+
+// How long to wait for the rest of an incomplete escape sequence,
+// before returning its inputs, like a lone `Esc` key.
+pub(crate) const ESCAPE_DELAY: Duration = Duration::from_millis(25);
+
+// Recognizes the events in a stream of inputs.
+//
+// The terminal sequences of pastes and focus changes are not recognized
+// by notcurses, and arrive as an `Esc` key, or an `Alt` modified `[`,
+// followed by the rest of the characters.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EventParser {
+    pub(crate) paste: bool,
+    pub(crate) focus: bool,
+    // the inputs of an escape sequence that is not yet complete.
+    pending: Vec<Input>,
+    // the text of a paste that is not yet complete.
+    pasting: Option<String>,
+    ready: VecDeque<Event>,
+}
+
+impl EventParser {
+    // Returns a new parser, recognizing the enabled sequences.
+    pub(crate) fn new(paste: bool, focus: bool) -> Self {
+        Self {
+            paste,
+            focus,
+            ..Default::default()
+        }
+    }
+
+    // Returns `true` if any sequence is recognized.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.paste || self.focus
+    }
+
+    // Returns `true` if an escape sequence is incomplete, outside of a paste,
+    // which must be flushed as inputs if nothing else is received.
+    pub(crate) fn is_waiting(&self) -> bool {
+        !self.pending.is_empty() && self.pasting.is_none()
+    }
+
+    // Returns the next recognized event.
+    pub(crate) fn pop(&mut self) -> Option<Event> {
+        self.ready.pop_front()
+    }
+
+    // Turns the inputs of an incomplete escape sequence into input events,
+    // unless a paste is in progress.
+    pub(crate) fn flush(&mut self) {
+        if self.pasting.is_none() {
+            self.flush_pending();
+        }
+    }
+
+    // Feeds the next `input`.
+    pub(crate) fn feed(&mut self, input: Input) {
+        if !self.is_enabled() {
+            self.ready.push_back(input.into());
+            return;
+        }
+        if input.is_release() || !input.received() {
+            if self.pending.is_empty() && self.pasting.is_none() {
+                self.ready.push_back(input.into());
+            }
+            return;
+        }
+
+        self.pending.push(input);
+        let sequence: Option<String> = self.pending.iter().map(sequence_part).collect();
+        if let Some(sequence) = sequence {
+            let mut is_prefix = false;
+            for target in self.sequences() {
+                if sequence == target {
+                    self.pending.clear();
+                    self.complete(target);
+                    return;
+                }
+                is_prefix |= target.starts_with(&sequence);
+            }
+            if is_prefix {
+                return;
+            }
+        }
+
+        // the last input could start a new sequence.
+        if self.pending.len() > 1 {
+            let last = self.pending.pop().expect("pending input");
+            self.flush_pending();
+            self.feed(last);
+        } else {
+            self.flush_pending();
+        }
+    }
+
+    // Returns the sequences recognized in the current state.
+    fn sequences(&self) -> Vec<&'static str> {
+        let mut sequences = vec![];
+        if self.pasting.is_some() {
+            sequences.push(PASTE_END);
+        } else {
+            if self.paste {
+                sequences.push(PASTE_START);
+            }
+            if self.focus {
+                sequences.extend([FOCUS_IN, FOCUS_OUT]);
+            }
+        }
+        sequences
+    }
+
+    // Handles a complete `sequence`.
+    fn complete(&mut self, sequence: &str) {
+        match sequence {
+            PASTE_START => self.pasting = Some(String::new()),
+            PASTE_END => {
+                let text = self.pasting.take().unwrap_or_default();
+                self.ready.push_back(Event::Paste(text));
+            }
+            FOCUS_IN => self.ready.push_back(Event::FocusGained),
+            FOCUS_OUT => self.ready.push_back(Event::FocusLost),
+            _ => unreachable!(),
+        }
+    }
+
+    // Moves the pending inputs to the paste text, or to the ready events.
+    fn flush_pending(&mut self) {
+        for input in self.pending.drain(..) {
+            match &mut self.pasting {
+                Some(text) => match sequence_part(&input) {
+                    Some(part) => text.push_str(&part),
+                    None => text.extend(pasted_char(&input)),
+                },
+                None => self.ready.push_back(input.into()),
+            }
+        }
+    }
+}
+
+// Returns the part of an escape sequence that the `input` represents, if any.
+fn sequence_part(input: &Input) -> Option<String> {
+    let keymod = input.keymod & !(KeyMod::Shift | KeyMod::CapsLock | KeyMod::NumLock);
+    match (input.received, keymod) {
+        (Received::Key(Key::Esc), KeyMod::None) => Some("\x1b".into()),
+        (Received::Char('['), KeyMod::Alt) => Some("\x1b[".into()),
+        (Received::Char(c), KeyMod::None) => Some(c.into()),
+        _ => None,
+    }
+}
+
+// Returns the character that the `input` adds to a paste, if any.
+fn pasted_char(input: &Input) -> Option<char> {
+    match input.received {
+        Received::Char(c) => Some(c),
+        Received::Key(Key::Enter) => Some('\n'),
+        Received::Key(Key::Tab) => Some('\t'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Event, EventParser};
+    use crate::input::{Input, InputType, Key, KeyMod, Received};
+
+    // Returns the inputs that notcurses reports for the `string`.
+    fn inputs(string: &str) -> Vec<Input> {
+        string
+            .chars()
+            .map(|c| match c {
+                '\x1b' => Input::from(Key::Esc),
+                '\n' => Input::from(Key::Enter),
+                c => Input::from(c),
+            })
+            .collect()
+    }
+
+    fn feed(parser: &mut EventParser, inputs: impl IntoIterator<Item = Input>) -> Vec<Event> {
+        for input in inputs {
+            parser.feed(input);
+        }
+        core::iter::from_fn(|| parser.pop()).collect()
+    }
+
+    fn chars(string: &str) -> Vec<Event> {
+        inputs(string).into_iter().map(Event::from).collect()
+    }
+
+    #[test]
+    fn paste_and_focus() {
+        let mut parser = EventParser::new(true, true);
+        let events = feed(&mut parser, inputs("a\x1b[200~b\nc\x1b[201~\x1b[I\x1b[Od"));
+        assert_eq![
+            vec![
+                Event::from(Input::from('a')),
+                Event::Paste("b\nc".into()),
+                Event::FocusGained,
+                Event::FocusLost,
+                Event::from(Input::from('d')),
+            ],
+            events
+        ];
+
+        // notcurses may report an `Esc [` as an `Alt` modified `[`.
+        let mut alt = vec![Input::new('[', KeyMod::Alt)];
+        alt.extend(inputs("I"));
+        assert_eq![vec![Event::FocusGained], feed(&mut parser, alt)];
+    }
+
+    #[test]
+    fn paste_nested_esc() {
+        let mut parser = EventParser::new(true, true);
+        let mut events = feed(&mut parser, inputs("\x1b[200~a\x1bb\x1b\x1b[I\x1b[20"));
+        assert![events.is_empty()];
+        // a paste is not flushed while it's incomplete.
+        parser.flush();
+        assert![!parser.is_waiting()];
+        events.extend(feed(&mut parser, inputs("1~")));
+        assert_eq![vec![Event::Paste("a\x1bb\x1b\x1b[I".into())], events];
+    }
+
+    #[test]
+    fn restart_on_mismatch() {
+        let mut parser = EventParser::new(true, true);
+        // the second `Esc` starts the actual sequence.
+        let mut expected = chars("\x1b");
+        expected.push(Event::FocusGained);
+        assert_eq![expected, feed(&mut parser, inputs("\x1b\x1b[I"))];
+
+        // a mismatch returns the inputs, but the `Esc` may start a new sequence.
+        let mut expected = chars("\x1b[20");
+        expected.push(Event::FocusLost);
+        assert_eq![expected, feed(&mut parser, inputs("\x1b[20\x1b[O"))];
+
+        let mut expected = chars("\x1b[2x");
+        expected.push(Event::Paste("p".into()));
+        assert_eq![
+            expected,
+            feed(&mut parser, inputs("\x1b[2x\x1b[200~p\x1b[201~"))
+        ];
+    }
+
+    #[test]
+    fn incomplete_flush() {
+        let mut parser = EventParser::new(true, true);
+        assert![feed(&mut parser, inputs("\x1b[2")).is_empty()];
+        assert![parser.is_waiting()];
+        parser.flush();
+        assert![!parser.is_waiting()];
+        assert_eq![chars("\x1b[2"), feed(&mut parser, [])];
+    }
+
+    #[test]
+    fn release_and_no_input() {
+        let release = Input {
+            itype: InputType::Release,
+            ..Input::from('x')
+        };
+        let none = Input::from(Received::NoInput);
+        let mut parser = EventParser::new(true, true);
+
+        // they're ignored in the middle of a sequence, or a paste,
+        let mut sequence = inputs("\x1b[");
+        sequence.extend([release, none]);
+        sequence.extend(inputs("I\x1b[200~a"));
+        sequence.extend([release, none]);
+        sequence.extend(inputs("b\x1b[201~"));
+        assert_eq![
+            vec![Event::FocusGained, Event::Paste("ab".into())],
+            feed(&mut parser, sequence)
+        ];
+
+        // but returned otherwise.
+        assert_eq![
+            vec![Event::from(release), Event::from(none)],
+            feed(&mut parser, [release, none])
+        ];
+    }
+
+    #[test]
+    fn disabled() {
+        let mut parser = EventParser::new(false, true);
+        let mut expected = chars("\x1b[200~");
+        expected.push(Event::FocusGained);
+        assert_eq![expected, feed(&mut parser, inputs("\x1b[200~\x1b[I"))];
+
+        let mut parser = EventParser::new(false, false);
+        assert![!parser.is_enabled()];
+        assert_eq![chars("\x1b[I"), feed(&mut parser, inputs("\x1b[I"))];
+    }
+}
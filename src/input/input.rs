@@ -9,7 +9,7 @@ use crate::{
 };
 
 /// A received input.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
 pub struct Input {
     /// The received input event.
     pub received: Received,
@@ -28,8 +28,8 @@ pub struct Input {
 }
 
 mod core_impls {
-    use super::{Input, Position};
-    use crate::sys::{NcInput, NcReceived};
+    use super::{Input, Key, KeyMod, Position, Received};
+    use crate::sys::{NcInput, NcInputType, NcReceived};
     use core::fmt;
 
     impl fmt::Display for Input {
@@ -83,6 +83,79 @@ mod core_impls {
             }
         }
     }
+
+    impl From<Input> for NcInput {
+        fn from(input: Input) -> NcInput {
+            let mut nc = NcInput::new_empty();
+            nc.id = NcReceived::from(input.received).into();
+            if let Some(c) = input.received.char() {
+                let mut utf8 = [0; 4];
+                for (i, b) in c.encode_utf8(&mut utf8).bytes().enumerate() {
+                    nc.utf8[i] = b as _;
+                }
+            }
+            (nc.x, nc.y) = input.cell.map(|c| c.into()).unwrap_or((-1, -1));
+            (nc.xpx, nc.ypx) = input.offset.map(|o| o.into()).unwrap_or((-1, -1));
+            nc.evtype = NcInputType::from(input.itype) as u32;
+            nc.modifiers = input.keymod.into();
+            // deprecated fields, still checked by some notcurses functions.
+            nc.alt = input.keymod.has_alt();
+            nc.shift = input.keymod.has_shift();
+            nc.ctrl = input.keymod.has_ctrl();
+            nc
+        }
+    }
+    impl From<&Input> for NcInput {
+        fn from(input: &Input) -> NcInput {
+            (*input).into()
+        }
+    }
+
+    impl From<Received> for Input {
+        fn from(received: Received) -> Input {
+            Input::new(received, KeyMod::None)
+        }
+    }
+    impl From<Key> for Input {
+        fn from(key: Key) -> Input {
+            Input::new(key, KeyMod::None)
+        }
+    }
+    impl From<char> for Input {
+        fn from(character: char) -> Input {
+            Input::new(character, KeyMod::None)
+        }
+    }
+}
+
+/// # constructors
+impl Input {
+    /// Returns a new `Input` with the provided `received` and `keymod`.
+    ///
+    /// This is useful for defining shortcuts, and for comparing with received inputs.
+    pub fn new(received: impl Into<Received>, keymod: KeyMod) -> Input {
+        Input {
+            received: received.into(),
+            keymod,
+            itype: InputType::Unknown,
+            cell: None,
+            offset: None,
+        }
+    }
+
+    /// Returns a new mouse `Input` of the provided `button` and `itype`,
+    /// at the `cell` position.
+    ///
+    /// The `button` can be any of the mouse buttons, or [`Key::Motion`].
+    pub fn mouse(button: Key, itype: InputType, cell: impl Into<Position>) -> Input {
+        Input {
+            received: button.into(),
+            keymod: KeyMod::None,
+            itype,
+            cell: Some(cell.into()),
+            offset: None,
+        }
+    }
 }
 
 /// # methods
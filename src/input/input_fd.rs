@@ -0,0 +1,57 @@
+// notcurses::input::input_fd
+//
+//!
+//
+
+use crate::sys::NcFd;
+
+/// A file descriptor suitable for polling the readiness of [`Input`] events.
+///
+/// When it becomes readable, [`poll_event`] ought to return some input.
+///
+/// This is not necessarily the file descriptor associated with *stdin*.
+///
+/// It doesn't borrow the [`Notcurses`] instance, so its input can be polled
+/// while it's being held, but it's only valid for as long as the instance
+/// lives, and it must not be closed.
+///
+/// [`Input`]: crate::Input
+/// [`poll_event`]: crate::Notcurses#method.poll_event
+/// [`Notcurses`]: crate::Notcurses
+///
+/// # Used by
+/// - [`Notcurses.input_fd`][crate::Notcurses#method.input_fd]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct InputFd {
+    fd: NcFd,
+}
+
+mod core_impls {
+    use super::InputFd;
+    use core::fmt;
+
+    impl fmt::Debug for InputFd {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "InputFd({})", self.fd)
+        }
+    }
+}
+
+#[cfg(unix)]
+mod std_impls {
+    use super::InputFd;
+    use std::os::unix::io::{AsRawFd, RawFd};
+
+    impl AsRawFd for InputFd {
+        fn as_raw_fd(&self) -> RawFd {
+            self.fd
+        }
+    }
+}
+
+/// # constructors
+impl InputFd {
+    pub(crate) fn new(fd: NcFd) -> Self {
+        Self { fd }
+    }
+}
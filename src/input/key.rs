@@ -9,7 +9,8 @@ pub struct Key(u32);
 
 mod core_impls {
     use super::{Key, NcKey};
-    use core::fmt;
+    use crate::{error::NotcursesError as Error, sys::c_api};
+    use core::{fmt, str::FromStr};
 
     impl fmt::Display for Key {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -23,6 +24,40 @@ mod core_impls {
         }
     }
 
+    /// Parses the name of a key, case-insensitively, like `"Up"` or `"F12"`.
+    ///
+    /// Some aliases are also accepted: `Return`, `CR`, `Escape`, `BS`,
+    /// `Insert`, `Delete`, `PageUp` and `PageDown`.
+    impl FromStr for Key {
+        type Err = Error;
+
+        fn from_str(name: &str) -> Result<Key, Error> {
+            let lowercase = name.to_ascii_lowercase();
+            let key = match lowercase.as_str() {
+                "" => None,
+                "return" | "ret" | "cr" => Some(Key::Enter),
+                "escape" => Some(Key::Esc),
+                "bs" => Some(Key::Backspace),
+                "insert" => Some(Key::Ins),
+                "delete" => Some(Key::Del),
+                "pageup" => Some(Key::PgUp),
+                "pagedown" => Some(Key::PgDown),
+                _ => match lowercase.strip_prefix('f').map(str::parse::<u32>) {
+                    Some(Ok(n)) if n <= 60 => Key::new(c_api::NCKEY_F00 + n),
+                    _ => {
+                        // the tab and escape keys are outside of the synthesized range.
+                        [c_api::NCKEY_TAB, c_api::NCKEY_ESC]
+                            .into_iter()
+                            .chain(c_api::NCKEY_INVALID + 1..=c_api::NCKEY_EOF)
+                            .find(|n| Key::check_name(*n).eq_ignore_ascii_case(name))
+                            .and_then(Key::new)
+                    }
+                },
+            };
+            key.ok_or_else(|| Error::Message(format!["Unknown key name {name:?}."]))
+        }
+    }
+
     impl From<NcKey> for Key {
         fn from(nc: NcKey) -> Key {
             Key(nc.0)
@@ -255,6 +290,11 @@ impl Key {
         matches!(self.0, c_api::NCKEY_MEDIA_PLAY..=c_api::NCKEY_MEDIA_MUTE)
     }
 
+    /// Returns `true` if it's a modifier key event, like `LShift` or `RCtrl`.
+    pub fn is_modifier(&self) -> bool {
+        matches!(self.0, c_api::NCKEY_LSHIFT..=c_api::NCKEY_L5SHIFT)
+    }
+
     /// Returns `true` if it's a mouse event.
     pub fn is_mouse(&self) -> bool {
         matches!(self.0, c_api::NCKEY_MOTION..=c_api::NCKEY_BUTTON11)
@@ -0,0 +1,290 @@
+// notcurses::input::key_combo
+//
+//!
+//
+
+use crate::input::{Input, Key, KeyMod, Received};
+
+/// A key press with its modifiers, like `Ctrl+x`, `Alt+Enter` or `Shift+F05`.
+///
+/// It can be parsed from several common notations:
+/// - modifiers separated by `+` or `-`: `"ctrl+shift+a"`, `"C-x"`, `"M-Enter"`.
+/// - vim-style, between angle brackets: `"<C-a>"`, `"<CR>"`, `"<S-Tab>"`.
+/// - caret notation for control characters: `"^A"`.
+/// - a single character or the name of a [`Key`]: `"q"`, `"F12"`, `"Space"`.
+///
+/// Modifier names are parsed with [`KeyMod::from_name`], and key names with
+/// the [`FromStr`] implementation of `Key`.
+///
+/// It's normalized on construction, so that it compares equal to the
+/// [`Input`]s it matches:
+/// - the `CapsLock` and `NumLock` modifiers are ignored.
+/// - the `Shift` modifier is ignored for characters, which are shifted instead,
+///   so `"shift+a"` is the same as `"A"`.
+/// - letters are lowercased when combined with any other modifier, since
+///   terminals differ in how they report e.g. `Ctrl+a`. This also applies to
+///   `Alt`, `Super`, etc., so `"Alt+A"` is the same as `"Alt+a"`, and
+///   whether the letter was shifted can't be told apart.
+///
+/// Its [`Display`] is canonical, so that parsing it back returns the same combo.
+///
+/// [`FromStr`]: core::str::FromStr
+/// [`Display`]: core::fmt::Display
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let combo: KeyCombo = "ctrl+shift+a".parse()?;
+/// assert_eq![combo, "<C-a>".parse()?];
+/// assert_eq![combo, "^A".parse()?];
+/// assert_eq!["Ctrl+a", combo.to_string()];
+///
+/// assert![combo.matches(&Input::new('A', KeyMod::Ctrl))];
+/// assert_eq![KeyCombo::new(Key::F12, KeyMod::None), "F12".parse()?];
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct KeyCombo {
+    /// The received key or character.
+    pub received: Received,
+
+    /// The keyboard modifiers.
+    pub keymod: KeyMod,
+}
+
+mod core_impls {
+    use super::{Key, KeyCombo, KeyMod, Received};
+    use crate::{error::NotcursesError as Error, input::Input};
+    use core::{fmt, str::FromStr};
+
+    impl fmt::Display for KeyCombo {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if !self.keymod.has_none() {
+                write!(f, "{}+", self.keymod)?;
+            }
+            match self.received {
+                Received::Char(' ') => f.write_str("Space"),
+                Received::Char(c) => write!(f, "{c}"),
+                Received::Key(k) => write!(f, "{k}"),
+                Received::NoInput => f.write_str("NoInput"),
+            }
+        }
+    }
+
+    impl fmt::Debug for KeyCombo {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "KeyCombo({self})")
+        }
+    }
+
+    impl FromStr for KeyCombo {
+        type Err = Error;
+
+        fn from_str(string: &str) -> Result<KeyCombo, Error> {
+            let string = string.trim();
+            let combo = if let Some(inner) = string
+                .strip_prefix('<')
+                .and_then(|s| s.strip_suffix('>'))
+                .filter(|s| !s.is_empty())
+            {
+                KeyCombo::parse_modified(inner)
+            } else if let Some(c) = caret_char(string) {
+                Some(KeyCombo::new(c, KeyMod::Ctrl))
+            } else {
+                KeyCombo::parse_modified(string)
+            };
+            combo.ok_or_else(|| Error::Message(format!["Invalid key combo {string:?}."]))
+        }
+    }
+
+    // Returns the character of a caret notation, like `^A`.
+    fn caret_char(string: &str) -> Option<char> {
+        let mut chars = string.strip_prefix('^')?.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    impl From<Input> for KeyCombo {
+        fn from(input: Input) -> KeyCombo {
+            KeyCombo::new(input.received, input.keymod)
+        }
+    }
+    impl From<&Input> for KeyCombo {
+        fn from(input: &Input) -> KeyCombo {
+            KeyCombo::new(input.received, input.keymod)
+        }
+    }
+    impl From<KeyCombo> for Input {
+        fn from(combo: KeyCombo) -> Input {
+            Input::new(combo.received, combo.keymod)
+        }
+    }
+
+    impl From<Key> for KeyCombo {
+        fn from(key: Key) -> KeyCombo {
+            KeyCombo::new(key, KeyMod::None)
+        }
+    }
+    impl From<char> for KeyCombo {
+        fn from(character: char) -> KeyCombo {
+            KeyCombo::new(character, KeyMod::None)
+        }
+    }
+}
+
+/// # constructors
+impl KeyCombo {
+    /// Returns a new normalized `KeyCombo`.
+    pub fn new(received: impl Into<Received>, keymod: KeyMod) -> KeyCombo {
+        let mut keymod = keymod & !(KeyMod::CapsLock | KeyMod::NumLock);
+        let mut received = received.into();
+        if let Received::Char(c) = received {
+            keymod &= !KeyMod::Shift;
+            if keymod != KeyMod::None && c.is_ascii_alphabetic() {
+                received = Received::Char(c.to_ascii_lowercase());
+            }
+        }
+        Self { received, keymod }
+    }
+
+    // Parses a key with optional leading modifiers separated by `+` or `-`.
+    fn parse_modified(string: &str) -> Option<KeyCombo> {
+        let mut keymod = KeyMod::None;
+        let mut rest = string;
+        while let Some((m, r)) = split_modifier(rest) {
+            keymod |= m;
+            rest = r;
+        }
+
+        let mut chars = rest.chars();
+        let mut received = match (chars.next(), chars.next()) {
+            (None, _) => return None,
+            (Some(c), None) => Received::Char(c),
+            _ if rest.eq_ignore_ascii_case("space") || rest.eq_ignore_ascii_case("spc") => {
+                Received::Char(' ')
+            }
+            _ => Received::Key(rest.parse::<Key>().ok()?),
+        };
+
+        // a shifted letter on its own is the uppercase letter.
+        if let Received::Char(c) = received {
+            if keymod == KeyMod::Shift && c.is_ascii_lowercase() {
+                received = Received::Char(c.to_ascii_uppercase());
+            }
+        }
+        Some(Self::new(received, keymod))
+    }
+}
+
+/// # methods
+impl KeyCombo {
+    /// Returns `true` if the `input` is this key combo.
+    pub fn matches(&self, input: &Input) -> bool {
+        Self::new(self.received, self.keymod) == input.into()
+    }
+}
+
+// Splits a leading modifier and its separator from the rest of the `string`.
+fn split_modifier(string: &str) -> Option<(KeyMod, &str)> {
+    let (i, _) = string
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == '-' || *c == '+')?;
+    let rest = &string[i + 1..];
+    if rest.is_empty() {
+        return None;
+    }
+    Some((KeyMod::from_name(&string[..i])?, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Key, KeyCombo, KeyMod, Received};
+    use crate::sys::c_api;
+
+    fn parse(string: &str) -> Option<KeyCombo> {
+        string.parse().ok()
+    }
+
+    #[test]
+    fn separators_as_keys() {
+        let combo = |c, keymod| Some(KeyCombo::new(c, keymod));
+        assert_eq![combo('-', KeyMod::Ctrl), parse("C--")];
+        assert_eq![combo('+', KeyMod::Ctrl), parse("C-+")];
+        assert_eq![combo('+', KeyMod::Ctrl), parse("Ctrl++")];
+        assert_eq![combo('-', KeyMod::Ctrl | KeyMod::Alt), parse("C-M--")];
+        assert_eq![combo('-', KeyMod::None), parse("-")];
+        assert_eq![combo('+', KeyMod::None), parse("+")];
+        assert_eq![combo('<', KeyMod::None), parse("<")];
+        assert_eq![combo('^', KeyMod::None), parse("^")];
+        assert_eq![None, parse("<>")];
+        assert_eq![None, parse("")];
+        assert_eq![None, parse("Ctrl+")];
+        assert_eq![None, parse("Foo+a")];
+    }
+
+    #[test]
+    fn shift_normalization() {
+        let shifted = KeyCombo::new('A', KeyMod::None);
+        assert_eq![Some(shifted), parse("A")];
+        assert_eq![Some(shifted), parse("shift+a")];
+        assert_eq![Some(shifted), parse("S-A")];
+        assert_eq![
+            shifted,
+            KeyCombo::new('A', KeyMod::Shift | KeyMod::CapsLock)
+        ];
+        assert_ne![Some(shifted), parse("a")];
+
+        // with other modifiers, the letter is lowercased.
+        let ctrl = KeyCombo::new('a', KeyMod::Ctrl);
+        assert_eq![ctrl, KeyCombo::new('A', KeyMod::Ctrl | KeyMod::Shift)];
+        assert_eq![Some(ctrl), parse("^A")];
+        assert_eq![Some(ctrl), parse("<C-S-a>")];
+        assert_eq![parse("Alt+a"), parse("Alt+A")];
+        assert_eq![Received::Char('a'), parse("M-A").unwrap().received];
+
+        // non-character keys keep the shift modifier.
+        let shift_tab = KeyCombo::new(Key::Tab, KeyMod::Shift);
+        assert_eq![Some(shift_tab), parse("<S-Tab>")];
+        assert_eq!["Shift+Tab", shift_tab.to_string()];
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        let modifiers = [
+            KeyMod::Shift,
+            KeyMod::Alt,
+            KeyMod::Ctrl,
+            KeyMod::Super,
+            KeyMod::Hyper,
+            KeyMod::Meta,
+        ];
+        let keys = [c_api::NCKEY_TAB, c_api::NCKEY_ESC]
+            .into_iter()
+            .chain(c_api::NCKEY_INVALID + 1..=c_api::NCKEY_EOF)
+            .filter_map(Key::new)
+            // the codes without a name are unused.
+            .filter(|key| !key.name().is_empty())
+            .map(Received::Key);
+        let chars = ['a', 'Z', '1', '-', '+', '<', '>', '^', '#', ' ', 'ñ'].map(Received::Char);
+
+        let mut tested_keys = 0;
+        for received in keys.chain(chars) {
+            tested_keys += 1;
+            for bits in 0..1 << modifiers.len() {
+                let keymod = modifiers
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| bits & 1 << i != 0)
+                    .fold(KeyMod::None, |keymod, (_, m)| keymod | *m);
+                let combo = KeyCombo::new(received, keymod);
+                assert_eq![Some(combo), parse(&combo.to_string()), "{combo}"];
+            }
+        }
+        assert![tested_keys > 100];
+    }
+}
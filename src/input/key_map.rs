@@ -0,0 +1,429 @@
+// notcurses::input::key_map
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::{Input, InputType, KeyCombo, Received},
+};
+use core::{str::FromStr, time::Duration};
+use std::time::Instant;
+
+/// A map of key bindings to user actions, with multi-key chords and named modes.
+///
+/// Each binding is a key spec made of one or more whitespace-separated
+/// [`KeyCombo`]s, like `"q"`, `"C-x C-s"`, `"<M-CR>"` or `"Shift+F5"`.
+///
+/// Bindings belong to a named mode, like in vim. Only the bindings of the
+/// current mode are active, which is initially the [`DEFAULT_MODE`].
+///
+/// The inputs are [`feed`]ed one by one. The key presses of a chord must
+/// follow each other within the [`timeout`], or the chord starts over.
+///
+/// [`DEFAULT_MODE`]: KeyMap::DEFAULT_MODE
+/// [`feed`]: KeyMap#method.feed
+/// [`timeout`]: KeyMap#method.timeout
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let mut keymap = KeyMap::new();
+/// keymap.bind("C-x C-s", "save")?;
+/// keymap.bind("q", "quit")?;
+/// keymap.bind_in("insert", "Esc", "normal")?;
+///
+/// let ctrl_x = Input::new('x', KeyMod::Ctrl);
+/// let ctrl_s = Input::new('s', KeyMod::Ctrl);
+/// assert_eq![KeyMapResult::Pending, keymap.feed(&ctrl_x)];
+/// assert_eq![KeyMapResult::Action("save"), keymap.feed(&ctrl_s)];
+///
+/// keymap.set_mode("insert");
+/// assert_eq![KeyMapResult::Unbound, keymap.feed(&Input::from('q'))];
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Configuration
+///
+/// A `KeyMap` can be parsed from a configuration string with
+/// [`from_config`], and written back with [`Display`]. Each line binds
+/// a key spec to an action, separated by ` = `, and `[mode]` lines start
+/// the bindings of a mode. Empty lines and lines starting with `#` are ignored,
+/// so a binding that starts with the `#` key must write it as `<#>`.
+///
+/// ```txt
+/// C-x C-s = save
+/// q = quit
+///
+/// [insert]
+/// Esc = normal
+/// ```
+///
+/// [`from_config`]: KeyMap#method.from_config
+/// [`Display`]: core::fmt::Display
+#[derive(Clone)]
+pub struct KeyMap<A> {
+    // the bindings of each mode, in insertion order.
+    modes: Vec<(String, Bindings<A>)>,
+    mode: String,
+    timeout: Duration,
+    pending: Vec<KeyCombo>,
+    last_press: Option<Instant>,
+}
+
+// The key press sequences bound to actions.
+type Bindings<A> = Vec<(Vec<KeyCombo>, A)>;
+
+/// The result of [`feed`]ing an [`Input`] to a [`KeyMap`].
+///
+/// [`feed`]: KeyMap#method.feed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyMapResult<A> {
+    /// The input completed a binding, returning its action.
+    Action(A),
+
+    /// The input continues a chord, waiting for more key presses.
+    Pending,
+
+    /// The input doesn't match any binding of the current mode.
+    ///
+    /// Any pending chord is discarded.
+    Unbound,
+
+    /// The input is not a key press, like a key release or a modifier key,
+    /// and was ignored.
+    Ignored,
+}
+
+mod core_impls {
+    use super::KeyMap;
+    use core::fmt;
+
+    impl<A> Default for KeyMap<A> {
+        fn default() -> Self {
+            Self {
+                modes: vec![],
+                mode: KeyMap::<A>::DEFAULT_MODE.into(),
+                timeout: KeyMap::<A>::DEFAULT_TIMEOUT,
+                pending: vec![],
+                last_press: None,
+            }
+        }
+    }
+
+    impl<A: fmt::Display> fmt::Display for KeyMap<A> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            // the default mode goes first, without a header.
+            let mut modes: Vec<_> = self.modes.iter().collect();
+            modes.sort_by_key(|(mode, _)| mode.as_str() != KeyMap::<A>::DEFAULT_MODE);
+
+            for (i, (mode, bindings)) in modes.into_iter().enumerate() {
+                if mode.as_str() != KeyMap::<A>::DEFAULT_MODE {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    writeln!(f, "[{mode}]")?;
+                }
+                for (sequence, action) in bindings {
+                    let mut spec = KeyMap::<A>::sequence_to_string(sequence);
+                    // a leading `#` or `[` key would be read back as a comment or a mode.
+                    if spec.starts_with(['#', '[']) {
+                        spec = format!["<{}>{}", &spec[..1], &spec[1..]];
+                    }
+                    writeln!(f, "{spec} = {action}")?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl<A: fmt::Debug> fmt::Debug for KeyMap<A> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let modes: Vec<_> = self.modes.iter().map(|(mode, b)| (mode, b.len())).collect();
+            f.debug_struct("KeyMap")
+                .field("mode", &self.mode)
+                .field("modes", &modes)
+                .field("timeout", &self.timeout)
+                .field("pending", &KeyMap::<A>::sequence_to_string(&self.pending))
+                .finish()
+        }
+    }
+}
+
+/// # constants
+impl<A> KeyMap<A> {
+    /// The name of the initial mode.
+    pub const DEFAULT_MODE: &'static str = "default";
+
+    /// The initial maximum time between the key presses of a chord.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+}
+
+/// # constructors
+impl<A> KeyMap<A> {
+    /// Returns a new empty `KeyMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `KeyMap` from a `config` string.
+    ///
+    /// See the [configuration format][KeyMap#configuration].
+    ///
+    /// # Errors
+    /// - if a line is not a binding nor a mode.
+    /// - if a key spec or an action can't be parsed.
+    /// - if a key spec conflicts with another one.
+    pub fn from_config(config: &str) -> Result<Self>
+    where
+        A: FromStr,
+    {
+        let mut keymap = Self::new();
+        let mut mode = Self::DEFAULT_MODE.to_string();
+        for (number, line) in config.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                mode = name.trim().into();
+                continue;
+            }
+            let (spec, action) = match line.split_once(" = ") {
+                Some(binding) => binding,
+                None => return Error::msg(&format!["Invalid binding at line {}.", number + 1]),
+            };
+            let action = match action.trim().parse() {
+                Ok(action) => action,
+                Err(_) => return Error::msg(&format!["Invalid action at line {}.", number + 1]),
+            };
+            keymap.bind_in(&mode, spec, action)?;
+        }
+        Ok(keymap)
+    }
+}
+
+/// # methods
+impl<A> KeyMap<A> {
+    /// Binds the key `spec` to the `action` in the default mode.
+    ///
+    /// See [`bind_in`][KeyMap#method.bind_in].
+    pub fn bind(&mut self, spec: &str, action: A) -> Result<()> {
+        self.bind_in(Self::DEFAULT_MODE, spec, action)
+    }
+
+    /// Binds the key `spec` to the `action` in the `mode`.
+    ///
+    /// Replaces the action of the same key spec, if it was already bound.
+    ///
+    /// # Errors
+    /// - if the `spec` can't be parsed.
+    /// - if the `spec` is a chord prefix of another binding, or the other way
+    ///   around, like `C-x` and `C-x C-s`.
+    pub fn bind_in(&mut self, mode: &str, spec: &str, action: A) -> Result<()> {
+        let sequence = match parse_sequence(spec) {
+            Some(sequence) => sequence,
+            None => return Error::msg(&format!["Invalid key spec {spec:?}."]),
+        };
+
+        let bindings = self.mode_bindings_mut(mode);
+        if let Some((_, bound)) = bindings.iter_mut().find(|(s, _)| *s == sequence) {
+            *bound = action;
+            return Ok(());
+        }
+        if let Some((other, _)) = bindings
+            .iter()
+            .find(|(s, _)| s.starts_with(&sequence) || sequence.starts_with(s))
+        {
+            let other = Self::sequence_to_string(other);
+            return Error::msg(&format!["Key spec {spec:?} conflicts with {other:?}."]);
+        }
+        bindings.push((sequence, action));
+        Ok(())
+    }
+
+    /// Unbinds the key `spec` in the default mode, returning its action.
+    pub fn unbind(&mut self, spec: &str) -> Option<A> {
+        self.unbind_in(Self::DEFAULT_MODE, spec)
+    }
+
+    /// Unbinds the key `spec` in the `mode`, returning its action.
+    pub fn unbind_in(&mut self, mode: &str, spec: &str) -> Option<A> {
+        let sequence = parse_sequence(spec)?;
+        let bindings = self.mode_bindings_mut(mode);
+        let index = bindings.iter().position(|(s, _)| *s == sequence)?;
+        Some(bindings.remove(index).1)
+    }
+
+    /// Returns the bindings of the `mode`, with their canonical key specs.
+    pub fn bindings(&self, mode: &str) -> Vec<(String, &A)> {
+        self.modes
+            .iter()
+            .find(|(m, _)| m == mode)
+            .map(|(_, bindings)| {
+                bindings
+                    .iter()
+                    .map(|(s, action)| (Self::sequence_to_string(s), action))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the current mode.
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+
+    /// Sets the current mode, discarding any pending chord.
+    ///
+    /// The mode doesn't need to have any bindings.
+    pub fn set_mode(&mut self, mode: &str) {
+        self.mode = mode.into();
+        self.reset();
+    }
+
+    /// Returns the maximum time between the key presses of a chord.
+    ///
+    /// Default: *[`DEFAULT_TIMEOUT`][KeyMap::DEFAULT_TIMEOUT]*.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sets the maximum time between the key presses of a chord.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Returns `true` if there's a chord waiting for more key presses.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Discards any pending chord.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.last_press = None;
+    }
+
+    /// Feeds an `input`, returning the action of the binding it completes, if any.
+    ///
+    /// A pending chord is discarded first if the [`timeout`] has expired
+    /// since its last key press.
+    ///
+    /// [`timeout`]: KeyMap#method.timeout
+    pub fn feed(&mut self, input: &Input) -> KeyMapResult<A>
+    where
+        A: Clone,
+    {
+        let is_modifier = matches!(input.received, Received::Key(k) if k.is_modifier());
+        if input.itype == InputType::Release || input.received == Received::NoInput || is_modifier {
+            return KeyMapResult::Ignored;
+        }
+
+        let now = Instant::now();
+        if matches!(self.last_press, Some(last) if now - last > self.timeout) {
+            self.pending.clear();
+        }
+        self.pending.push(KeyCombo::from(input));
+        self.last_press = Some(now);
+
+        let mut is_prefix = false;
+        for (sequence, action) in self.mode_bindings() {
+            if *sequence == self.pending {
+                let action = action.clone();
+                self.reset();
+                return KeyMapResult::Action(action);
+            }
+            is_prefix |= sequence.starts_with(&self.pending);
+        }
+        if is_prefix {
+            KeyMapResult::Pending
+        } else {
+            self.reset();
+            KeyMapResult::Unbound
+        }
+    }
+}
+
+// private methods
+impl<A> KeyMap<A> {
+    // Returns the bindings of the current mode.
+    fn mode_bindings(&self) -> &[(Vec<KeyCombo>, A)] {
+        self.modes
+            .iter()
+            .find(|(m, _)| *m == self.mode)
+            .map(|(_, bindings)| bindings.as_slice())
+            .unwrap_or_default()
+    }
+
+    // Returns the bindings of the `mode`, adding the mode if it doesn't exist.
+    fn mode_bindings_mut(&mut self, mode: &str) -> &mut Bindings<A> {
+        let index = match self.modes.iter().position(|(m, _)| m == mode) {
+            Some(index) => index,
+            None => {
+                self.modes.push((mode.into(), vec![]));
+                self.modes.len() - 1
+            }
+        };
+        &mut self.modes[index].1
+    }
+
+    // Returns the canonical key spec of a sequence.
+    fn sequence_to_string(sequence: &[KeyCombo]) -> String {
+        let specs: Vec<String> = sequence.iter().map(|s| s.to_string()).collect();
+        specs.join(" ")
+    }
+}
+
+// Parses a key spec made of whitespace-separated key combos, like `C-x C-s`.
+fn parse_sequence(spec: &str) -> Option<Vec<KeyCombo>> {
+    let sequence: Option<Vec<KeyCombo>> = spec.split_whitespace().map(|s| s.parse().ok()).collect();
+    sequence.filter(|s| !s.is_empty())
+}
+
+/// # methods
+impl<A> KeyMapResult<A> {
+    /// Returns the action, if any.
+    pub fn action(self) -> Option<A> {
+        match self {
+            Self::Action(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the input completed a binding.
+    pub fn is_action(&self) -> bool {
+        matches!(self, Self::Action(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyMap;
+
+    #[test]
+    fn config_roundtrip() {
+        let config =
+            "<#> = hash\n<[> g = bracket\n# a comment\nC-x C-s = save\n\n[insert]\nEsc = normal\n";
+        let keymap = KeyMap::<String>::from_config(config).unwrap();
+        assert_eq![
+            vec![
+                ("#".to_string(), &"hash".to_string()),
+                ("[ g".to_string(), &"bracket".to_string()),
+                ("Ctrl+x Ctrl+s".to_string(), &"save".to_string()),
+            ],
+            keymap.bindings(KeyMap::<String>::DEFAULT_MODE)
+        ];
+
+        let written = keymap.to_string();
+        assert_eq![
+            "<#> = hash\n<[> g = bracket\nCtrl+x Ctrl+s = save\n\n[insert]\nEsc = normal\n",
+            written
+        ];
+        let reread = KeyMap::<String>::from_config(&written).unwrap();
+        assert_eq![keymap.to_string(), reread.to_string()];
+        assert_eq![keymap.bindings("insert"), reread.bindings("insert")];
+    }
+}
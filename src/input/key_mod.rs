@@ -45,7 +45,8 @@ impl KeyMod {
 
 mod core_impls {
     use super::{KeyMod, NcKeyMod};
-    use core::fmt;
+    use crate::error::NotcursesError as Error;
+    use core::{fmt, str::FromStr};
 
     impl Default for KeyMod {
         fn default() -> Self {
@@ -91,6 +92,24 @@ mod core_impls {
         }
     }
 
+    /// Parses modifier names separated by `+` or `-`, like `"Ctrl+Shift"` or `"C-M"`.
+    ///
+    /// See [`KeyMod::from_name`] for the accepted names.
+    impl FromStr for KeyMod {
+        type Err = Error;
+
+        fn from_str(string: &str) -> Result<KeyMod, Error> {
+            if string.eq_ignore_ascii_case("none") {
+                return Ok(KeyMod::None);
+            }
+            string
+                .split(['+', '-'])
+                .map(|name| KeyMod::from_name(name.trim()))
+                .try_fold(KeyMod::None, |acc, m| m.map(|m| acc | m))
+                .ok_or_else(|| Error::Message(format!["Invalid key modifiers {string:?}."]))
+        }
+    }
+
     impl fmt::Debug for KeyMod {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             write!(f, "KeyMod::{}", self)
@@ -125,6 +144,35 @@ mod core_impls {
 
 /// # methods
 impl KeyMod {
+    /// Returns the modifier with the provided `name`, if any.
+    ///
+    /// Full names are case-insensitive, like `ctrl`, `control`, `shift`,
+    /// `alt`, `meta`, `super`, `hyper`, `capslock` and `numlock`.
+    ///
+    /// The emacs-style abbreviations are case-sensitive: `C` (ctrl),
+    /// `M` or `A` (alt), `S` (shift), `s` (super) and `H` (hyper).
+    pub fn from_name(name: &str) -> Option<KeyMod> {
+        let keymod = match name {
+            "C" => KeyMod::Ctrl,
+            "M" | "A" => KeyMod::Alt,
+            "S" => KeyMod::Shift,
+            "s" => KeyMod::Super,
+            "H" => KeyMod::Hyper,
+            _ => match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyMod::Ctrl,
+                "shift" => KeyMod::Shift,
+                "alt" => KeyMod::Alt,
+                "meta" => KeyMod::Meta,
+                "super" => KeyMod::Super,
+                "hyper" => KeyMod::Hyper,
+                "capslock" => KeyMod::CapsLock,
+                "numlock" => KeyMod::NumLock,
+                _ => return None,
+            },
+        };
+        Some(keymod)
+    }
+
     /// Returns `true` if no modifiers are present.
     #[inline]
     pub fn has_none(&self) -> bool {
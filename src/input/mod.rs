@@ -3,16 +3,27 @@
 //!
 //
 
+mod event;
 mod input;
+mod input_fd;
 mod input_type;
 mod key;
+mod key_combo;
+mod key_map;
 mod key_mod;
 mod mice_events;
+mod mouse_tracker;
 mod received;
 
+pub use event::Event;
+pub(crate) use event::{EventParser, ESCAPE_DELAY};
 pub use input::Input;
+pub use input_fd::InputFd;
 pub use input_type::InputType;
 pub use key::Key;
+pub use key_combo::KeyCombo;
+pub use key_map::{KeyMap, KeyMapResult};
 pub use key_mod::KeyMod;
 pub use mice_events::MiceEvents;
+pub use mouse_tracker::{MouseEvent, MouseTracker};
 pub use received::Received;
@@ -0,0 +1,660 @@
+// notcurses::input::mouse_tracker
+//
+//!
+//
+
+use crate::{
+    input::{Input, InputType, Key, KeyMod, Received},
+    Position,
+};
+use core::time::Duration;
+use std::time::Instant;
+
+/// Recognizes mouse gestures from a stream of mouse [`Input`]s.
+///
+/// The raw mouse inputs are [`feed`]ed one by one, and turned into
+/// [`MouseEvent`]s: clicks, double-clicks, drags and scrolls.
+///
+/// A press and release of the same button is a click, or a double-click when
+/// it follows a previous click of the same button in the same cell within the
+/// [`double_click_time`]. Moving a pressed button at least
+/// [`drag_threshold`] cells away from where it was pressed starts a drag,
+/// which ends when the button is released.
+///
+/// Mouse events must be enabled with [`Notcurses::mice_enable`], using
+/// [`MiceEvents::Drag`] or `All` in order to receive the movements of a drag.
+///
+/// [`feed`]: MouseTracker#method.feed
+/// [`double_click_time`]: MouseTracker#method.double_click_time
+/// [`drag_threshold`]: MouseTracker#method.drag_threshold
+/// [`Notcurses::mice_enable`]: crate::Notcurses#method.mice_enable
+/// [`MiceEvents::Drag`]: crate::MiceEvents#associatedconstant.Drag
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// let mut tracker = MouseTracker::new();
+///
+/// let press = Input::mouse(Key::Button1, InputType::Press, (2, 3));
+/// let release = Input::mouse(Key::Button1, InputType::Release, (2, 3));
+/// assert_eq![None, tracker.feed(&press)];
+/// assert![matches![tracker.feed(&release), Some(MouseEvent::Click { .. })]];
+/// assert_eq![None, tracker.feed(&press)];
+/// assert![matches![tracker.feed(&release), Some(MouseEvent::DoubleClick { .. })]];
+///
+/// tracker.feed(&press);
+/// let moved = Input::mouse(Key::Button1, InputType::Press, (5, 3));
+/// assert![matches![tracker.feed(&moved), Some(MouseEvent::DragStart { .. })]];
+/// let event = tracker.feed(&Input::mouse(Key::Button1, InputType::Release, (6, 4)));
+/// assert_eq![Some(Position::new(2, 3)), event.and_then(|e| e.start())];
+/// assert_eq![Some(Position::new(6, 4)), event.map(|e| e.cell())];
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MouseTracker {
+    double_click_time: Duration,
+    drag_threshold: u32,
+    pressed: Option<Pressed>,
+    last_click: Option<(Key, Position, Instant)>,
+}
+
+// The state of a pressed mouse button.
+#[derive(Clone, Copy, Debug)]
+struct Pressed {
+    button: Key,
+    keymod: KeyMod,
+    start: Position,
+    cell: Position,
+    offset: Option<Position>,
+    dragging: bool,
+}
+
+/// A mouse gesture, recognized by a [`MouseTracker`].
+///
+/// The `cell` is the position of the pointer, and the `offset` its pixel
+/// offset within the cell, if the terminal reports it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseEvent {
+    /// A button was pressed and released without being dragged.
+    Click {
+        button: Key,
+        keymod: KeyMod,
+        cell: Position,
+        offset: Option<Position>,
+    },
+
+    /// A button was clicked twice in the same cell.
+    DoubleClick {
+        button: Key,
+        keymod: KeyMod,
+        cell: Position,
+        offset: Option<Position>,
+    },
+
+    /// A pressed button started being dragged from the `start` cell.
+    DragStart {
+        button: Key,
+        keymod: KeyMod,
+        start: Position,
+        cell: Position,
+        offset: Option<Position>,
+    },
+
+    /// A dragged button moved to another cell.
+    DragMove {
+        button: Key,
+        keymod: KeyMod,
+        start: Position,
+        cell: Position,
+        offset: Option<Position>,
+    },
+
+    /// A dragged button was released.
+    DragEnd {
+        button: Key,
+        keymod: KeyMod,
+        start: Position,
+        cell: Position,
+        offset: Option<Position>,
+    },
+
+    /// The wheel was scrolled.
+    ///
+    /// The `button` is [`ScrollUp`], [`Scrolldown`], or [`Button6`] and
+    /// [`Button7`] for horizontal scrolling.
+    ///
+    /// [`ScrollUp`]: Key::ScrollUp
+    /// [`Scrolldown`]: Key::Scrolldown
+    /// [`Button6`]: Key::Button6
+    /// [`Button7`]: Key::Button7
+    Scroll {
+        button: Key,
+        keymod: KeyMod,
+        cell: Position,
+        offset: Option<Position>,
+    },
+}
+
+mod core_impls {
+    use super::MouseTracker;
+
+    impl Default for MouseTracker {
+        fn default() -> Self {
+            Self {
+                double_click_time: MouseTracker::DEFAULT_DOUBLE_CLICK_TIME,
+                drag_threshold: MouseTracker::DEFAULT_DRAG_THRESHOLD,
+                pressed: None,
+                last_click: None,
+            }
+        }
+    }
+}
+
+/// # constants
+impl MouseTracker {
+    /// The initial maximum time between the clicks of a double-click.
+    pub const DEFAULT_DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
+
+    /// The initial minimum distance in cells for a press to start a drag.
+    pub const DEFAULT_DRAG_THRESHOLD: u32 = 1;
+}
+
+/// # constructors
+impl MouseTracker {
+    /// Returns a new `MouseTracker`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// # methods
+impl MouseTracker {
+    /// Returns the maximum time between the clicks of a double-click.
+    pub fn double_click_time(&self) -> Duration {
+        self.double_click_time
+    }
+
+    /// Sets the maximum time between the clicks of a double-click.
+    pub fn set_double_click_time(&mut self, time: Duration) {
+        self.double_click_time = time;
+    }
+
+    /// Returns the minimum distance in cells for a press to start a drag.
+    pub fn drag_threshold(&self) -> u32 {
+        self.drag_threshold
+    }
+
+    /// Sets the minimum distance in cells for a press to start a drag.
+    ///
+    /// The distance is the largest of the horizontal and vertical distances.
+    /// A threshold of `0` is treated as `1`.
+    pub fn set_drag_threshold(&mut self, cells: u32) {
+        self.drag_threshold = cells;
+    }
+
+    /// Returns the button being pressed, if any.
+    pub fn pressed(&self) -> Option<Key> {
+        self.pressed.map(|p| p.button)
+    }
+
+    /// Returns `true` if a button is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        matches!(self.pressed, Some(p) if p.dragging)
+    }
+
+    /// Forgets any pressed button and previous click.
+    pub fn reset(&mut self) {
+        self.pressed = None;
+        self.last_click = None;
+    }
+
+    /// Feeds an `input`, returning the mouse gesture it completes, if any.
+    ///
+    /// Inputs that are not mouse events, or without a cell position, are ignored.
+    ///
+    /// Pressing a different button while another one is pressed starts
+    /// tracking the new one, ending the drag of the previous one if any.
+    pub fn feed(&mut self, input: &Input) -> Option<MouseEvent> {
+        let button = match input.received {
+            Received::Key(k) if k.is_mouse() => k,
+            _ => return None,
+        };
+        let (cell, offset, keymod) = (input.cell?, input.offset, input.keymod);
+
+        if Self::is_scroll(button) {
+            if input.itype == InputType::Release {
+                return None;
+            }
+            return Some(MouseEvent::Scroll {
+                button,
+                keymod,
+                cell,
+                offset,
+            });
+        }
+
+        if input.itype == InputType::Release {
+            return match self.pressed {
+                Some(p) if p.button == button || button == Key::Motion => {
+                    self.pressed = None;
+                    self.release(p, keymod, cell, offset)
+                }
+                _ => None,
+            };
+        }
+
+        match self.pressed {
+            // a movement of the pressed button
+            Some(mut p) if p.button == button || button == Key::Motion => {
+                if cell == p.cell {
+                    return None;
+                }
+                (p.cell, p.offset) = (cell, offset);
+                let event = if p.dragging {
+                    Some(MouseEvent::DragMove {
+                        button: p.button,
+                        keymod,
+                        start: p.start,
+                        cell,
+                        offset,
+                    })
+                } else if Self::distance(p.start, cell) >= self.drag_threshold.max(1) {
+                    p.dragging = true;
+                    self.last_click = None;
+                    Some(MouseEvent::DragStart {
+                        button: p.button,
+                        keymod,
+                        start: p.start,
+                        cell,
+                        offset,
+                    })
+                } else {
+                    None
+                };
+                self.pressed = Some(p);
+                event
+            }
+            // a movement without any pressed button
+            None if button == Key::Motion => None,
+            // a new press
+            previous => {
+                self.pressed = Some(Pressed {
+                    button,
+                    keymod,
+                    start: cell,
+                    cell,
+                    offset,
+                    dragging: false,
+                });
+                match previous {
+                    Some(p) if p.dragging => Some(MouseEvent::DragEnd {
+                        button: p.button,
+                        keymod: p.keymod,
+                        start: p.start,
+                        cell: p.cell,
+                        offset: p.offset,
+                    }),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+// private methods
+impl MouseTracker {
+    // Returns the gesture completed by releasing the pressed button.
+    fn release(
+        &mut self,
+        pressed: Pressed,
+        keymod: KeyMod,
+        cell: Position,
+        offset: Option<Position>,
+    ) -> Option<MouseEvent> {
+        let button = pressed.button;
+        if pressed.dragging {
+            return Some(MouseEvent::DragEnd {
+                button,
+                keymod,
+                start: pressed.start,
+                cell,
+                offset,
+            });
+        }
+        if Self::distance(pressed.start, cell) >= self.drag_threshold.max(1) {
+            // released away from where it was pressed, without reported movements.
+            self.last_click = None;
+            return None;
+        }
+
+        let now = Instant::now();
+        let is_double = matches!(self.last_click,
+            Some((b, c, time)) if b == button && c == cell && now - time <= self.double_click_time);
+        if is_double {
+            self.last_click = None;
+            Some(MouseEvent::DoubleClick {
+                button,
+                keymod,
+                cell,
+                offset,
+            })
+        } else {
+            self.last_click = Some((button, cell, now));
+            Some(MouseEvent::Click {
+                button,
+                keymod,
+                cell,
+                offset,
+            })
+        }
+    }
+
+    // Returns `true` if the `button` is a scroll wheel button.
+    fn is_scroll(button: Key) -> bool {
+        matches!(
+            button,
+            Key::Button4 | Key::Button5 | Key::Button6 | Key::Button7
+        )
+    }
+
+    // Returns the largest of the horizontal and vertical distances, in cells.
+    fn distance(from: Position, to: Position) -> u32 {
+        from.x().abs_diff(to.x()).max(from.y().abs_diff(to.y()))
+    }
+}
+
+/// # methods
+impl MouseEvent {
+    /// Returns the mouse button of the event.
+    pub fn button(&self) -> Key {
+        match *self {
+            MouseEvent::Click { button, .. }
+            | MouseEvent::DoubleClick { button, .. }
+            | MouseEvent::DragStart { button, .. }
+            | MouseEvent::DragMove { button, .. }
+            | MouseEvent::DragEnd { button, .. }
+            | MouseEvent::Scroll { button, .. } => button,
+        }
+    }
+
+    /// Returns the keyboard modifiers of the event.
+    pub fn keymod(&self) -> KeyMod {
+        match *self {
+            MouseEvent::Click { keymod, .. }
+            | MouseEvent::DoubleClick { keymod, .. }
+            | MouseEvent::DragStart { keymod, .. }
+            | MouseEvent::DragMove { keymod, .. }
+            | MouseEvent::DragEnd { keymod, .. }
+            | MouseEvent::Scroll { keymod, .. } => keymod,
+        }
+    }
+
+    /// Returns the cell position of the pointer.
+    pub fn cell(&self) -> Position {
+        match *self {
+            MouseEvent::Click { cell, .. }
+            | MouseEvent::DoubleClick { cell, .. }
+            | MouseEvent::DragStart { cell, .. }
+            | MouseEvent::DragMove { cell, .. }
+            | MouseEvent::DragEnd { cell, .. }
+            | MouseEvent::Scroll { cell, .. } => cell,
+        }
+    }
+
+    /// Returns the pixel offset of the pointer within the cell, if reported.
+    pub fn offset(&self) -> Option<Position> {
+        match *self {
+            MouseEvent::Click { offset, .. }
+            | MouseEvent::DoubleClick { offset, .. }
+            | MouseEvent::DragStart { offset, .. }
+            | MouseEvent::DragMove { offset, .. }
+            | MouseEvent::DragEnd { offset, .. }
+            | MouseEvent::Scroll { offset, .. } => offset,
+        }
+    }
+
+    /// Returns the cell where the drag started, if it's a drag event.
+    pub fn start(&self) -> Option<Position> {
+        match *self {
+            MouseEvent::DragStart { start, .. }
+            | MouseEvent::DragMove { start, .. }
+            | MouseEvent::DragEnd { start, .. } => Some(start),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if it's a drag event.
+    pub fn is_drag(&self) -> bool {
+        self.start().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MouseEvent, MouseTracker};
+    use crate::{
+        input::{Input, InputType, Key, KeyMod},
+        Position,
+    };
+    use core::time::Duration;
+
+    fn press(button: Key, cell: (u32, u32)) -> Input {
+        Input::mouse(button, InputType::Press, cell)
+    }
+    fn release(button: Key, cell: (u32, u32)) -> Input {
+        Input::mouse(button, InputType::Release, cell)
+    }
+
+    fn clicked(button: Key, cell: (u32, u32)) -> Option<MouseEvent> {
+        Some(MouseEvent::Click {
+            button,
+            keymod: KeyMod::None,
+            cell: cell.into(),
+            offset: None,
+        })
+    }
+
+    fn drag(start: (u32, u32), cell: (u32, u32)) -> (Position, Position) {
+        (start.into(), cell.into())
+    }
+
+    #[test]
+    fn click() {
+        let mut tracker = MouseTracker::new();
+        assert_eq![None, tracker.feed(&press(Key::Button1, (2, 3)))];
+        assert_eq![Some(Key::Button1), tracker.pressed()];
+        assert_eq![
+            clicked(Key::Button1, (2, 3)),
+            tracker.feed(&release(Key::Button1, (2, 3)))
+        ];
+        assert_eq![None, tracker.pressed()];
+
+        // the release of another button is ignored.
+        tracker.feed(&press(Key::Button3, (2, 3)));
+        assert_eq![None, tracker.feed(&release(Key::Button1, (2, 3)))];
+        assert_eq![
+            clicked(Key::Button3, (2, 3)),
+            tracker.feed(&release(Key::Button3, (2, 3)))
+        ];
+
+        // as are the inputs that are not mouse events.
+        assert_eq![None, tracker.feed(&Input::from('a'))];
+    }
+
+    #[test]
+    fn double_click() {
+        let mut tracker = MouseTracker::new();
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        assert_eq![
+            clicked(Key::Button1, (2, 3)),
+            tracker.feed(&release(Key::Button1, (2, 3)))
+        ];
+
+        // the next click in the same cell is a double-click,
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        assert_eq![
+            Some(MouseEvent::DoubleClick {
+                button: Key::Button1,
+                keymod: KeyMod::None,
+                cell: Position::new(2, 3),
+                offset: None,
+            }),
+            tracker.feed(&release(Key::Button1, (2, 3)))
+        ];
+        // and the next one starts counting again.
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        assert_eq![
+            clicked(Key::Button1, (2, 3)),
+            tracker.feed(&release(Key::Button1, (2, 3)))
+        ];
+
+        // a click in another cell, or with another button, is not a double-click.
+        tracker.feed(&press(Key::Button1, (4, 3)));
+        assert_eq![
+            clicked(Key::Button1, (4, 3)),
+            tracker.feed(&release(Key::Button1, (4, 3)))
+        ];
+        tracker.feed(&press(Key::Button2, (4, 3)));
+        assert_eq![
+            clicked(Key::Button2, (4, 3)),
+            tracker.feed(&release(Key::Button2, (4, 3)))
+        ];
+    }
+
+    #[test]
+    fn double_click_time() {
+        let mut tracker = MouseTracker::new();
+        tracker.set_double_click_time(Duration::from_millis(1));
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        tracker.feed(&release(Key::Button1, (2, 3)));
+        std::thread::sleep(Duration::from_millis(10));
+
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        assert_eq![
+            clicked(Key::Button1, (2, 3)),
+            tracker.feed(&release(Key::Button1, (2, 3)))
+        ];
+    }
+
+    #[test]
+    fn drag_threshold() {
+        let mut tracker = MouseTracker::new();
+        tracker.set_drag_threshold(3);
+        tracker.feed(&press(Key::Button1, (10, 10)));
+
+        // moving closer than the threshold doesn't start a drag,
+        assert_eq![None, tracker.feed(&press(Key::Button1, (12, 11)))];
+        assert_eq![
+            None,
+            tracker.feed(&Input::mouse(Key::Motion, InputType::Press, (8, 12)))
+        ];
+        assert![!tracker.is_dragging()];
+
+        // reaching it does, whatever the direction,
+        let event = tracker.feed(&press(Key::Button1, (10, 7)));
+        assert![matches![
+            event,
+            Some(MouseEvent::DragStart {
+                button: Key::Button1,
+                ..
+            })
+        ]];
+        assert_eq![
+            Some(drag((10, 10), (10, 7))),
+            event.map(|e| (e.start().unwrap(), e.cell()))
+        ];
+        assert![tracker.is_dragging()];
+
+        // then any movement to another cell is reported,
+        let event = tracker.feed(&press(Key::Button1, (11, 7)));
+        assert![matches![event, Some(MouseEvent::DragMove { .. })]];
+        assert_eq![None, tracker.feed(&press(Key::Button1, (11, 7)))];
+
+        // until it's released.
+        let event = tracker.feed(&release(Key::Button1, (11, 8)));
+        assert![matches![event, Some(MouseEvent::DragEnd { .. })]];
+        assert_eq![
+            Some(drag((10, 10), (11, 8))),
+            event.map(|e| (e.start().unwrap(), e.cell()))
+        ];
+        assert![!tracker.is_dragging()];
+
+        // a release under the threshold is still a click.
+        tracker.feed(&press(Key::Button1, (10, 10)));
+        assert_eq![
+            clicked(Key::Button1, (12, 12)),
+            tracker.feed(&release(Key::Button1, (12, 12)))
+        ];
+    }
+
+    #[test]
+    fn release_away_without_motion() {
+        let mut tracker = MouseTracker::new();
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        tracker.feed(&release(Key::Button1, (2, 3)));
+
+        // it's neither a click nor a drag,
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        assert_eq![None, tracker.feed(&release(Key::Button1, (9, 3)))];
+        assert_eq![None, tracker.pressed()];
+
+        // and it breaks the double-click.
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        assert_eq![
+            clicked(Key::Button1, (2, 3)),
+            tracker.feed(&release(Key::Button1, (2, 3)))
+        ];
+    }
+
+    #[test]
+    fn second_button_mid_drag() {
+        let mut tracker = MouseTracker::new();
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        tracker.feed(&press(Key::Button1, (4, 3)));
+        assert![tracker.is_dragging()];
+
+        // the drag of the first button ends where it was last seen,
+        let event = tracker.feed(&Input::mouse(Key::Button3, InputType::Press, (5, 5)));
+        assert_eq![
+            Some(MouseEvent::DragEnd {
+                button: Key::Button1,
+                keymod: KeyMod::None,
+                start: Position::new(2, 3),
+                cell: Position::new(4, 3),
+                offset: None,
+            }),
+            event
+        ];
+        // and the second button is tracked instead.
+        assert_eq![Some(Key::Button3), tracker.pressed()];
+        assert![!tracker.is_dragging()];
+        assert_eq![None, tracker.feed(&release(Key::Button1, (5, 5)))];
+        assert_eq![
+            clicked(Key::Button3, (5, 5)),
+            tracker.feed(&release(Key::Button3, (5, 5)))
+        ];
+
+        // pressing another button while not dragging ends nothing.
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        assert_eq![None, tracker.feed(&press(Key::Button2, (2, 3)))];
+        assert_eq![Some(Key::Button2), tracker.pressed()];
+    }
+
+    #[test]
+    fn scroll() {
+        let mut tracker = MouseTracker::new();
+        for button in [Key::Button4, Key::Button5, Key::Button6, Key::Button7] {
+            let event = tracker.feed(&press(button, (1, 1)));
+            assert![matches![event, Some(MouseEvent::Scroll { button: b, .. }) if b == button]];
+            // its releases are ignored.
+            assert_eq![None, tracker.feed(&release(button, (1, 1)))];
+        }
+        assert_eq![None, tracker.pressed()];
+
+        // and scrolling doesn't interrupt a drag.
+        tracker.feed(&press(Key::Button1, (2, 3)));
+        tracker.feed(&press(Key::Button1, (4, 3)));
+        tracker.feed(&press(Key::Button4, (4, 3)));
+        tracker.feed(&release(Key::Button4, (4, 3)));
+        assert![tracker.is_dragging()];
+    }
+}
@@ -55,7 +55,7 @@ impl Received {
 }
 
 mod core_impls {
-    use super::Received;
+    use super::{Key, Received};
     use crate::sys::NcReceived;
     use core::fmt;
 
@@ -86,6 +86,17 @@ mod core_impls {
         }
     }
 
+    impl From<Key> for Received {
+        fn from(k: Key) -> Self {
+            Received::Key(k)
+        }
+    }
+    impl From<char> for Received {
+        fn from(c: char) -> Self {
+            Received::Char(c)
+        }
+    }
+
     impl From<NcReceived> for Received {
         fn from(nc: NcReceived) -> Self {
             match nc {
@@ -13,23 +13,46 @@
 
 use core::cell::RefCell;
 use once_cell::sync::OnceCell;
+use std::collections::HashMap;
 
 mod color;
+mod direct;
 mod error;
 mod input;
 mod macros;
 mod notcurses;
 mod plane;
+mod session;
 mod visual;
+mod widgets;
 
+#[cfg(all(feature = "tokio", unix))]
+pub use self::notcurses::EventStream;
 pub use self::notcurses::{Capabilities, LogLevel, Notcurses, NotcursesBuilder, Statistics};
 pub use color::{Alpha, Channel, Channels, Palette, Rgb, Rgba};
+pub use direct::{Direct, DirectBuilder};
 pub use error::{NotcursesError, NotcursesResult};
-pub use input::{Input, InputType, Key, KeyMod, MiceEvents, Received};
-pub use plane::{Align, Cell, Plane, PlaneBuilder, PlaneGeometry, Style};
+pub use input::{
+    Event, Input, InputFd, InputType, Key, KeyCombo, KeyMap, KeyMapResult, KeyMod, MiceEvents,
+    MouseEvent, MouseTracker, Received,
+};
+pub use plane::{
+    Align, BoxChannels, BoxEdges, BoxStyle, Cell, FadeContext, Plane, PlaneBuilder, PlaneGeometry,
+    PlaneId, PlaneSnapshot, ResizeCallback, SnapshotCell, Style, UPDATE_SNAPSHOTS_VAR,
+};
+pub use session::Replayer;
 pub use visual::{
     Blitter, PixelImplementation, Scale, Visual, VisualBuilder, VisualGeometry, VisualOptions,
 };
+pub use widgets::{
+    Completer, LineEditor, LineEditorAction, LineEditorBuilder, Menu, MenuAction, MenuBuilder,
+    MenuItem, MenuSection, MultiSelector, MultiSelectorBuilder, Plot, PlotBuilder, PlotDomain,
+    PlotSample, ProgressBar, ProgressBarBuilder, ProgressDirection, Reel, ReelBuilder, Selector,
+    SelectorAction, SelectorBuilder, SelectorItem, TabCallback, TabId, Tabbed, TabbedBuilder,
+    Tablet, TabletId,
+};
+#[cfg(unix)]
+pub use widgets::{FdPlane, FdPlaneBuilder, Subprocess, SubprocessBuilder};
 
 //
 
@@ -37,8 +60,20 @@ thread_local!(
     /// Restricts initializing more than one `Notcurses` instance per thread, at the same time.
     static NOTCURSES_LOCK: RefCell<OnceCell<bool>> = RefCell::new(OnceCell::new());
 
+    /// Restricts initializing more than one `Direct` instance per thread, at the same time.
+    static DIRECT_LOCK: RefCell<OnceCell<bool>> = const { RefCell::new(OnceCell::new()) };
+
     /// Restricts instancing the standard `Plane` more than once per `Notcurses` instance.
     static CLI_PLANE_LOCK: RefCell<OnceCell<bool>> = RefCell::new(OnceCell::new());
+
+    /// Stores the resize callbacks of the `Plane`s, indexed by their address.
+    static RESIZE_CALLBACKS: RefCell<HashMap<usize, ResizeCallback>> = RefCell::new(HashMap::new());
+
+    /// Stores the current session recording, if any.
+    static RECORDER: RefCell<Option<session::Recorder>> = const { RefCell::new(None) };
+
+    /// Stores the error that stopped the current session recording early, if any.
+    static RECORDER_ERROR: RefCell<Option<NotcursesError>> = const { RefCell::new(None) };
 );
 
 /// Reexport of [`libnotcurses-sys`](https://docs.rs/libnotcurses-sys).
@@ -52,4 +87,4 @@ pub(crate) use sys::unit_impl_fmt;
 pub(crate) use sys::unit_impl_ops;
 
 #[doc(inline)]
-pub use cuadra::{Position32 as Position, Size32 as Size};
+pub use cuadra::{Position32 as Position, Size32 as Size, Zone32 as Zone};
@@ -15,7 +15,7 @@
 /// ```
 /// # use notcurses::*;
 /// # fn main() -> NotcursesResult<()> {
-/// # let mut nc = Notcurses::new_cli()?;
+/// # let mut nc = Notcurses::headless((80, 24))?;
 /// # let mut plane = Plane::new(&mut nc)?;
 /// # plane.set_scrolling(true);
 /// assert_eq![12, putstr!(plane, "hello\nworld\n")?];
@@ -54,7 +54,7 @@ macro_rules! putstr {
 /// ```
 /// # use notcurses::*;
 /// # fn main() -> NotcursesResult<()> {
-/// # let mut nc = Notcurses::new_cli()?;
+/// # let mut nc = Notcurses::headless((80, 24))?;
 /// # let mut plane = Plane::new(&mut nc)?;
 /// # plane.set_scrolling(true);
 /// assert_eq![12, putstrln!(plane, "hello world")?];
@@ -92,3 +92,46 @@ macro_rules! putstrln {
         }) as $crate::NotcursesResult<u32>
     };
 }
+
+/// Asserts that a plane matches its golden snapshot file.
+///
+/// Takes a [`PlaneSnapshot`] of the `plane`, and compares it against the
+/// file `tests/snapshots/<name>.snap`, relative to the manifest directory
+/// of the crate being tested.
+///
+/// If the [`UPDATE_SNAPSHOTS_VAR`] environment variable is set,
+/// the file is written instead.
+///
+/// [`PlaneSnapshot`]: crate::PlaneSnapshot
+/// [`UPDATE_SNAPSHOTS_VAR`]: crate::UPDATE_SNAPSHOTS_VAR
+///
+/// # Panics
+/// - if the plane doesn't match the snapshot, printing a colorized diff.
+/// - if the snapshot file doesn't exist.
+/// - if the snapshot can't be taken, read or written.
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let mut nc = Notcurses::headless((80, 24))?;
+/// let mut plane = Plane::new_sized(&mut nc, (20, 3))?;
+/// plane.perimeter(BoxStyle::Rounded, Channels::new(), Style::None, BoxEdges::All)?;
+/// assert_plane_snapshot!(plane, "rounded_box");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_plane_snapshot {
+    ($plane:expr, $name:expr) => {
+        $plane
+            .snapshot()
+            .expect("Couldn't take a snapshot of the plane.")
+            .assert_matches(
+                ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests")
+                    .join("snapshots")
+                    .join(format!["{}.snap", $name]),
+            )
+    };
+}
@@ -3,16 +3,27 @@
 //!
 //
 
+#[cfg(unix)]
+use crate::{
+    error::NotcursesError as Error,
+    notcurses::headless::{Headless, HEADLESS_TERM},
+    sys::c_api,
+    Size,
+};
 use crate::{
     error::NotcursesResult as Result,
+    input::EventParser,
     notcurses::{LogLevel, Notcurses},
     sys::{Nc, NcOptionsBuilder},
 };
+use std::collections::VecDeque;
 
 /// A [`Notcurses`] builder.
 #[derive(Clone, Copy, Debug)]
 pub struct NotcursesBuilder {
     options: NcOptionsBuilder,
+    bracketed_paste: bool,
+    focus_events: bool,
 }
 
 mod core_impls {
@@ -22,6 +33,8 @@ mod core_impls {
         fn default() -> Self {
             Self {
                 options: NcOptionsBuilder::new().suppress_banners(true),
+                bracketed_paste: false,
+                focus_events: false,
             }
         }
     }
@@ -38,9 +51,70 @@ impl NotcursesBuilder {
     pub fn build(self) -> Result<Notcurses> {
         Notcurses::lock_notcurses()?;
         let nc = unsafe { Nc::with_options(self.options.build())? };
-        Ok(Notcurses {
+        let notcurses = Notcurses {
             nc,
             options: self.options,
+            injected: VecDeque::new(),
+            replaying: VecDeque::new(),
+            events: EventParser::new(self.bracketed_paste, self.focus_events),
+            #[cfg(unix)]
+            headless: None,
+        };
+        notcurses.set_terminal_modes(true);
+        Ok(notcurses)
+    }
+
+    /// Returns a headless `Notcurses` instance of a fixed `size`,
+    /// that doesn't need a real terminal.
+    ///
+    /// It renders to a new pseudo-terminal of the given size, whose output is
+    /// discarded, and uses the `xterm-256color` terminfo entry, which must be
+    /// installed, for a fixed capability set. It doesn't install any signal
+    /// handlers.
+    ///
+    /// This allows running code that uses planes, visuals and input in tests
+    /// and in CI. Note that notcurses reads the input from the standard input
+    /// when it's a terminal, so it's best isolated when it's not.
+    ///
+    /// # Errors
+    /// - if the `size` has a zero or too big dimension.
+    /// - if the pseudo-terminal can't be opened.
+    /// - if notcurses fails to initialize.
+    ///
+    /// # Example
+    /// ```
+    /// # use notcurses::*;
+    /// # fn main() -> NotcursesResult<()> {
+    /// let mut nc = NotcursesBuilder::new().build_headless((80, 24))?;
+    /// let mut plane = Plane::new(&mut nc)?;
+    /// assert_eq![Size::new(80, 24), plane.size()];
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub fn build_headless(self, size: impl Into<Size>) -> Result<Notcurses> {
+        let headless = Headless::new(size.into())?;
+        Notcurses::lock_notcurses()?;
+
+        let options = self
+            .options
+            .no_quit_sig_handlers(true)
+            .no_winch_sig_handler(true);
+        let mut ncoptions = options.build();
+        ncoptions.termtype = HEADLESS_TERM.as_ptr().cast();
+
+        let nc = unsafe { c_api::notcurses_init(&ncoptions, headless.file.cast()) };
+        if nc.is_null() {
+            Notcurses::unlock_notcurses();
+            return Error::msg("Couldn't initialize a headless `Notcurses`.");
+        }
+        Ok(Notcurses {
+            nc,
+            options,
+            injected: VecDeque::new(),
+            replaying: VecDeque::new(),
+            events: EventParser::new(self.bracketed_paste, self.focus_events),
+            headless: Some(headless),
         })
     }
 }
@@ -83,6 +157,25 @@ impl NotcursesBuilder {
         self
     }
 
+    /// If `true`, enables the bracketed paste mode of the terminal, so that
+    /// pasted text is received as a single [`Event::Paste`].
+    ///
+    /// [`Event::Paste`]: crate::Event#variant.Paste
+    pub fn bracketed_paste(mut self, enable: bool) -> Self {
+        self.bracketed_paste = enable;
+        self
+    }
+
+    /// If `true`, enables the focus reporting of the terminal, so that
+    /// [`Event::FocusGained`] and [`Event::FocusLost`] are received.
+    ///
+    /// [`Event::FocusGained`]: crate::Event#variant.FocusGained
+    /// [`Event::FocusLost`]: crate::Event#variant.FocusLost
+    pub fn focus_events(mut self, enable: bool) -> Self {
+        self.focus_events = enable;
+        self
+    }
+
     // flags
 
     /// If `true`, Input may be freely dropped.
@@ -0,0 +1,105 @@
+// notcurses::notcurses::event_stream
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::Input,
+    notcurses::Notcurses,
+};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+use std::os::unix::io::{AsRawFd, RawFd};
+use tokio::{io::unix::AsyncFd, runtime::Handle};
+
+/// An asynchronous stream of [`Input`] events.
+///
+/// It's driven by the [`input_fd`] readiness, so it doesn't need a polling thread.
+///
+/// Note that since [`Notcurses`] can't be sent between threads, this stream must
+/// be polled from the same thread, e.g. by using a *current thread* runtime,
+/// or a [`LocalSet`].
+///
+/// [`input_fd`]: Notcurses#method.input_fd
+/// [`LocalSet`]: https://docs.rs/tokio/latest/tokio/task/struct.LocalSet.html
+///
+/// # Example
+/// ```ignore
+/// # use notcurses::*;
+/// use futures::StreamExt;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> NotcursesResult<()> {
+/// let mut nc = Notcurses::new()?;
+/// let mut events = nc.event_stream()?;
+/// while let Some(event) = events.next().await {
+///     if event?.is_char('q') {
+///         break;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Used by
+/// - [`Notcurses.event_stream`][Notcurses#method.event_stream]
+pub struct EventStream<'nc> {
+    nc: &'nc mut Notcurses,
+    fd: AsyncFd<RawFd>,
+}
+
+mod core_impls {
+    use super::EventStream;
+    use core::fmt;
+
+    impl fmt::Debug for EventStream<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "EventStream {{ fd:{} }}", self.fd.get_ref())
+        }
+    }
+}
+
+/// # constructors
+impl<'nc> EventStream<'nc> {
+    /// Returns a new `EventStream` for the provided `notcurses` instance.
+    ///
+    /// # Errors
+    /// - if it's called outside of a *tokio* runtime.
+    /// - if the input file descriptor can't be obtained or registered.
+    ///
+    /// # Panics
+    /// - if the *tokio* runtime doesn't have IO enabled.
+    pub fn new(nc: &'nc mut Notcurses) -> Result<Self> {
+        if Handle::try_current().is_err() {
+            return Error::msg("`EventStream` must be created inside a tokio runtime.");
+        }
+        let fd = AsyncFd::new(nc.input_fd()?.as_raw_fd())?;
+        Ok(Self { nc, fd })
+    }
+}
+
+impl Stream for EventStream<'_> {
+    type Item = Result<Input>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            // there could already be queued events before the fd becomes ready.
+            match this.nc.poll_event() {
+                Ok(input) if input.received() => return Poll::Ready(Some(Ok(input))),
+                Ok(_) => (),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+
+            match this.fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(mut guard)) => guard.clear_ready(),
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// notcurses::notcurses::headless
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    sys::c_api::libc,
+    Size,
+};
+use core::{
+    ffi::c_char,
+    ptr::{null, null_mut},
+};
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    os::unix::io::FromRawFd,
+    thread::{self, JoinHandle},
+};
+
+/// The terminfo entry used by headless instances, for a fixed capability set.
+pub(super) const HEADLESS_TERM: &[u8] = b"xterm-256color\0";
+
+// The queries notcurses waits an answer for, and the replies of a basic terminal.
+const REPLIES: [(&[u8], &[u8]); 2] = [
+    // primary device attributes: a VT100 with advanced video option.
+    (b"\x1b[c", b"\x1b[?1;2c"),
+    // cursor position report: the upper-left corner.
+    (b"\x1b[6n", b"\x1b[1;1R"),
+];
+const MAX_QUERY_LEN: usize = 4;
+
+// A pseudo-terminal of a fixed size, standing in for a real terminal.
+//
+// A background thread drains everything notcurses writes to it,
+// and answers the queries notcurses needs answered to initialize.
+pub(crate) struct Headless {
+    // The terminal side of the pseudo-terminal, where notcurses writes.
+    pub(super) file: *mut libc::FILE,
+    // The controlling side of the pseudo-terminal, where the input is written.
+    input: File,
+    responder: Option<JoinHandle<()>>,
+}
+
+impl Drop for Headless {
+    fn drop(&mut self) {
+        // closing the terminal side makes the responder stop reading.
+        unsafe { libc::fclose(self.file) };
+        if let Some(responder) = self.responder.take() {
+            let _ = responder.join();
+        }
+    }
+}
+
+impl Headless {
+    // Opens a new pseudo-terminal of the given `size`.
+    pub(super) fn new(size: Size) -> Result<Self> {
+        let (cols, rows): (u32, u32) = size.into();
+        let (cols, rows) = match (u16::try_from(cols), u16::try_from(rows)) {
+            (Ok(cols @ 1..), Ok(rows @ 1..)) => (cols, rows),
+            _ => return Error::msg(&format!["Invalid headless size {cols}×{rows}."]),
+        };
+        let winsize = libc::winsize {
+            ws_row: rows,
+            ws_col: cols,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+
+        let (mut master, mut slave) = (-1, -1);
+        if unsafe { libc::openpty(&mut master, &mut slave, null_mut(), null(), &winsize) } < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        let master = unsafe { File::from_raw_fd(master) };
+        let input = match master.try_clone() {
+            Ok(input) => input,
+            Err(error) => {
+                unsafe { libc::close(slave) };
+                return Err(error.into());
+            }
+        };
+
+        let file = unsafe { libc::fdopen(slave, b"w\0".as_ptr() as *const c_char) };
+        if file.is_null() {
+            let error = io::Error::last_os_error();
+            unsafe { libc::close(slave) };
+            return Err(error.into());
+        }
+
+        let responder = thread::Builder::new()
+            .name("notcurses-headless".into())
+            .spawn(move || respond(master));
+        match responder {
+            Ok(responder) => Ok(Self {
+                file,
+                input,
+                responder: Some(responder),
+            }),
+            Err(error) => {
+                unsafe { libc::fclose(file) };
+                Err(error.into())
+            }
+        }
+    }
+
+    // Writes `bytes` to the terminal, to be read as input by notcurses.
+    pub(super) fn write_input(&mut self, bytes: &[u8]) -> Result<()> {
+        self.input.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+// Reads from the `master` side until the terminal side is closed,
+// replying to the known queries.
+fn respond(mut master: File) {
+    let mut buf = [0; 4096];
+    let mut pending = Vec::new();
+    while let Ok(len @ 1..) = master.read(&mut buf) {
+        pending.extend_from_slice(&buf[..len]);
+
+        // keeps the tail that could be the beginning of a query.
+        let mut consumed = pending.len().saturating_sub(MAX_QUERY_LEN - 1);
+        let mut i = 0;
+        while i < pending.len() {
+            match REPLIES
+                .iter()
+                .find(|(query, _)| pending[i..].starts_with(query))
+            {
+                Some((query, reply)) => {
+                    if master.write_all(reply).is_err() {
+                        return;
+                    }
+                    i += query.len();
+                    consumed = consumed.max(i);
+                }
+                None => i += 1,
+            }
+        }
+        pending.drain(..consumed);
+    }
+}
@@ -5,6 +5,10 @@
 
 mod builder;
 mod capabilities;
+#[cfg(all(feature = "tokio", unix))]
+mod event_stream;
+#[cfg(unix)]
+mod headless;
 mod log_level;
 mod notcurses;
 mod statistics;
@@ -12,5 +16,7 @@ mod statistics;
 pub use self::notcurses::Notcurses;
 pub use builder::NotcursesBuilder;
 pub use capabilities::Capabilities;
+#[cfg(all(feature = "tokio", unix))]
+pub use event_stream::EventStream;
 pub use log_level::LogLevel;
 pub use statistics::Statistics;
@@ -3,25 +3,39 @@
 //!
 //
 
+use core::time::Duration;
 use once_cell::sync::OnceCell;
+use std::{collections::VecDeque, io::Write, time::Instant};
 
+#[cfg(unix)]
+use super::headless::Headless;
 use super::{Capabilities, Statistics};
 use crate::{
     color::{Palette, Rgb},
     error::{NotcursesError as Error, NotcursesResult as Result},
-    input::{Input, MiceEvents},
-    plane::{Plane, PlaneGeometry, Style},
-    sys::{Nc, NcInput, NcOptionsBuilder},
+    input::{Event, EventParser, Input, InputFd, MiceEvents, ESCAPE_DELAY},
+    plane::{self, Plane, PlaneGeometry, PlaneId, Style},
+    session::{self, Replayer},
+    sys::{
+        c_api::{self, libc},
+        Nc, NcError, NcInput, NcOptionsBuilder, NcReceived, NcTime,
+    },
     visual::{Blitter, Visual, VisualGeometry},
     Position, Size, CLI_PLANE_LOCK, NOTCURSES_LOCK,
 };
 
 /// *Notcurses* state for a given terminal, composed of [`Plane`][crate::plane::Plane]s.
 ///
-/// There can only be a single `Notcurses` instance per thread at any given moment.
+/// There can only be a single `Notcurses` instance per thread at any given moment,
+/// and not while there's a [`Direct`][crate::Direct] instance.
 pub struct Notcurses {
     pub(super) nc: *mut Nc,
     pub(super) options: NcOptionsBuilder,
+    pub(super) injected: VecDeque<Input>,
+    pub(super) replaying: VecDeque<(Instant, Input)>,
+    pub(super) events: EventParser,
+    #[cfg(unix)]
+    pub(super) headless: Option<Headless>,
 }
 
 mod core_impls {
@@ -30,7 +44,10 @@ mod core_impls {
 
     impl Drop for Notcurses {
         fn drop(&mut self) {
+            let _ = crate::session::stop_recording();
+            self.set_terminal_modes(false);
             unsafe { self.into_ref_mut().drop_planes() };
+            crate::plane::forget_resize_callbacks();
             unsafe { self.into_ref_mut().stop().expect("Notcurses.drop()") };
             // Allows initializing a new Notcurses instance again.
             NOTCURSES_LOCK.with(|refcell| {
@@ -98,9 +115,15 @@ mod core_impls {
 
 // private functions
 impl Notcurses {
-    // Errors if there's already one `Notcurses` instance in this thread.
+    // Errors if there's already one `Notcurses` or `Direct` instance in this thread.
     // Activates the lock otherwise.
     pub(super) fn lock_notcurses() -> Result<()> {
+        // both modes can't share the terminal.
+        if crate::Direct::is_initialized() {
+            return Error::msg(
+                "A `Notcurses` instance can't be initialized while a `Direct` instance exists.",
+            );
+        }
         NOTCURSES_LOCK.with(|refcell| {
             let cell = refcell.borrow_mut();
             if cell.get().is_none() {
@@ -112,6 +135,48 @@ impl Notcurses {
         })
     }
 
+    // Returns the next injected input, or else the next replayed input,
+    // waiting for it if it's due within `wait`, or indefinitely if `None`.
+    fn next_queued_input(&mut self, wait: Option<Duration>) -> Option<Input> {
+        if let Some(input) = self.injected.pop_front() {
+            return Some(input);
+        }
+        let (due, _) = self.replaying.front()?;
+        let delay = due.saturating_duration_since(Instant::now());
+        if matches!(wait, Some(wait) if wait < delay) {
+            return None;
+        }
+        std::thread::sleep(delay);
+        self.replaying.pop_front().map(|(_, input)| input)
+    }
+
+    // Enables or disables the terminal modes of the recognized events.
+    pub(super) fn set_terminal_modes(&self, enable: bool) {
+        // the output of a headless instance doesn't go to the standard output.
+        if !self.events.is_enabled() || self.is_headless() {
+            return;
+        }
+        let mode = if enable { 'h' } else { 'l' };
+        let mut modes = String::new();
+        if self.events.paste {
+            modes += &format!["\x1b[?2004{mode}"];
+        }
+        if self.events.focus {
+            modes += &format!["\x1b[?1004{mode}"];
+        }
+        let mut stdout = std::io::stdout();
+        let _ = stdout
+            .write_all(modes.as_bytes())
+            .and_then(|_| stdout.flush());
+    }
+
+    // Releases the lock, after failing to initialize an instance.
+    pub(super) fn unlock_notcurses() {
+        NOTCURSES_LOCK.with(|refcell| {
+            refcell.replace(OnceCell::new());
+        });
+    }
+
     /// Returns `true` if there's already a notcurses instance initialized in this thread.
     pub fn is_initialized() -> bool {
         NOTCURSES_LOCK.with(|refcell| refcell.borrow().get().is_some())
@@ -138,7 +203,15 @@ impl Notcurses {
         Self::lock_notcurses()?;
         let options = NcOptionsBuilder::new().suppress_banners(true);
         let nc = unsafe { Nc::with_options(options.build())? };
-        Ok(Notcurses { nc, options })
+        Ok(Notcurses {
+            nc,
+            options,
+            injected: VecDeque::new(),
+            replaying: VecDeque::new(),
+            events: EventParser::default(),
+            #[cfg(unix)]
+            headless: None,
+        })
     }
 
     /// Returns a new `Notcurses` context, with banners.
@@ -146,7 +219,15 @@ impl Notcurses {
         Self::lock_notcurses()?;
         let options = NcOptionsBuilder::new();
         let nc = unsafe { Nc::with_options(options.build())? };
-        Ok(Notcurses { nc, options })
+        Ok(Notcurses {
+            nc,
+            options,
+            injected: VecDeque::new(),
+            replaying: VecDeque::new(),
+            events: EventParser::default(),
+            #[cfg(unix)]
+            headless: None,
+        })
     }
 
     /// Returns a new `Notcurses` context in `CLI` mode.
@@ -156,7 +237,53 @@ impl Notcurses {
             .suppress_banners(true)
             .cli_mode(true);
         let nc = unsafe { Nc::with_options(options.build())? };
-        Ok(Notcurses { nc, options })
+        Ok(Notcurses {
+            nc,
+            options,
+            injected: VecDeque::new(),
+            replaying: VecDeque::new(),
+            events: EventParser::default(),
+            #[cfg(unix)]
+            headless: None,
+        })
+    }
+
+    /// Returns a new headless `Notcurses` context of a fixed `size`,
+    /// that doesn't need a real terminal.
+    ///
+    /// See [`NotcursesBuilder::build_headless`] for details.
+    ///
+    /// [`NotcursesBuilder::build_headless`]: crate::NotcursesBuilder#method.build_headless
+    #[cfg(unix)]
+    pub fn headless(size: impl Into<Size>) -> Result<Self> {
+        crate::NotcursesBuilder::new().build_headless(size)
+    }
+
+    /// Returns `true` if this context is headless.
+    pub fn is_headless(&self) -> bool {
+        #[cfg(unix)]
+        return self.headless.is_some();
+        #[cfg(not(unix))]
+        return false;
+    }
+
+    /// Writes raw `bytes` to the terminal of a headless context,
+    /// to be received as input, as if they were typed.
+    ///
+    /// Unlike [injected inputs][Notcurses#method.inject_input], they go through
+    /// the input parsing, which allows testing escape sequences like pastes
+    /// and focus changes. Note that notcurses only reads them when the
+    /// standard input is not a terminal.
+    ///
+    /// # Errors
+    /// - if this context is not headless.
+    /// - if the `bytes` can't be written.
+    #[cfg(unix)]
+    pub fn write_headless_input(&mut self, bytes: &[u8]) -> Result<()> {
+        match self.headless.as_mut() {
+            Some(headless) => headless.write_input(bytes),
+            None => Error::msg("Only a headless `Notcurses` can be written input."),
+        }
     }
 
     /// Returns a new `Notcurses` context in `CLI` mode, with banners.
@@ -164,7 +291,15 @@ impl Notcurses {
         Self::lock_notcurses()?;
         let options = NcOptionsBuilder::new().cli_mode(true);
         let nc = unsafe { Nc::with_options(options.build())? };
-        Ok(Notcurses { nc, options })
+        Ok(Notcurses {
+            nc,
+            options,
+            injected: VecDeque::new(),
+            replaying: VecDeque::new(),
+            events: EventParser::default(),
+            #[cfg(unix)]
+            headless: None,
+        })
     }
 
     //
@@ -217,27 +352,337 @@ impl Notcurses {
     }
 
     /// Waits for an event, blocking.
+    ///
+    /// Returns the next [injected input][Notcurses#method.inject_input] first, if any.
     pub fn get_event(&mut self) -> Result<Input> {
-        let mut input = NcInput::new_empty();
-        let received = self.into_ref_mut().get_blocking(Some(&mut input))?;
-        Ok((received, input).into())
+        let input = match self.next_queued_input(None) {
+            Some(input) => input,
+            None => {
+                let mut input = NcInput::new_empty();
+                let received = self.into_ref_mut().get_blocking(Some(&mut input))?;
+                (received, input).into()
+            }
+        };
+        session::record_input(&input);
+        Ok(input)
     }
 
     /// Tries to get an event, non blocking.
+    ///
+    /// Returns the next [injected input][Notcurses#method.inject_input] first, if any.
     pub fn poll_event(&mut self) -> Result<Input> {
+        let input = match self.next_queued_input(Some(Duration::ZERO)) {
+            Some(input) => input,
+            None => {
+                let mut input = NcInput::new_empty();
+                let received = self.into_ref_mut().get_nblock(Some(&mut input))?;
+                (received, input).into()
+            }
+        };
+        session::record_input(&input);
+        Ok(input)
+    }
+
+    /// Waits for an event, blocking until the `timeout` expires.
+    ///
+    /// Returns an input with [`Received::NoInput`] if the `timeout` expired.
+    ///
+    /// Returns the next [injected input][Notcurses#method.inject_input] first, if any.
+    ///
+    /// [`Received::NoInput`]: crate::Received#variant.NoInput
+    pub fn poll_event_timeout(&mut self, timeout: Duration) -> Result<Input> {
+        if let Some(input) = self.next_queued_input(Some(timeout)) {
+            session::record_input(&input);
+            return Ok(input);
+        }
+        // the C API expects an absolute deadline, against `CLOCK_MONOTONIC`.
+        let mut now = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        if unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) } != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let deadline = Duration::new(now.tv_sec as u64, now.tv_nsec as u32)
+            .checked_add(timeout)
+            .and_then(|deadline| {
+                let secs = deadline.as_secs().try_into().ok()?;
+                Some(NcTime::new(secs, deadline.subsec_nanos() as _))
+            });
+        // a deadline too far away to be represented is the same as none.
+        let Some(deadline) = deadline else {
+            let mut input = NcInput::new_empty();
+            let received = self.into_ref_mut().get_blocking(Some(&mut input))?;
+            let input: Input = (received, input).into();
+            session::record_input(&input);
+            return Ok(input);
+        };
+
         let mut input = NcInput::new_empty();
-        let received = self.into_ref_mut().get_nblock(Some(&mut input))?;
-        Ok((received, input).into())
-    }
-
-    // /// Gets a file descriptor suitable for input event poll()ing.
-    // ///
-    // /// When this descriptor becomes available, you can call
-    // /// [poll_event][Notcurses#method.poll_event], and input ought be ready.
-    // ///
-    // pub fn input_ready(&mut self) -> Result<i32> {
-    //     Ok(self.into_ref_mut().inputready_fd()?)
-    // }
+        let res = unsafe { c_api::notcurses_get(self.nc, &deadline, &mut input) };
+        if res == c_api::NCRESULT_ERR as u32 {
+            return Err(
+                NcError::new_msg(&format!["Notcurses.poll_event_timeout({timeout:?})"]).into(),
+            );
+        }
+        let input: Input = (NcReceived::from(res), input).into();
+        session::record_input(&input);
+        Ok(input)
+    }
+
+    /// Waits for an event, blocking, recognizing pasted text and focus changes.
+    ///
+    /// It's like [`get_event`], but returns an [`Event`], which can also be a
+    /// [`Paste`] or a focus change, if enabled with
+    /// [`NotcursesBuilder.bracketed_paste`] and [`NotcursesBuilder.focus_events`].
+    ///
+    /// A paste is returned once it's complete. An `Esc` key is returned once
+    /// no other input follows it within a short delay of 25 milliseconds.
+    ///
+    /// [`get_event`]: Notcurses#method.get_event
+    /// [`Paste`]: crate::Event#variant.Paste
+    /// [`NotcursesBuilder.bracketed_paste`]: crate::NotcursesBuilder#method.bracketed_paste
+    /// [`NotcursesBuilder.focus_events`]: crate::NotcursesBuilder#method.focus_events
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use notcurses::*;
+    /// # fn main() -> NotcursesResult<()> {
+    /// let mut nc = NotcursesBuilder::new().bracketed_paste(true).build()?;
+    /// loop {
+    ///     match nc.get_extended_event()? {
+    ///         Event::Paste(text) => println!("pasted {} lines", text.lines().count()),
+    ///         Event::Input(input) if input.is_char('q') => break,
+    ///         _ => (),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_extended_event(&mut self) -> Result<Event> {
+        loop {
+            if let Some(event) = self.events.pop() {
+                return Ok(event);
+            }
+            if self.events.is_waiting() {
+                let input = self.poll_event_timeout(ESCAPE_DELAY)?;
+                if input.received() {
+                    self.events.feed(input);
+                } else {
+                    self.events.flush();
+                }
+            } else {
+                let input = self.get_event()?;
+                self.events.feed(input);
+            }
+        }
+    }
+
+    /// Tries to get an event, non blocking, recognizing pasted text and focus changes.
+    ///
+    /// It's like [`poll_event`], but returns an [`Event`].
+    /// See [`get_extended_event`] for details.
+    ///
+    /// Returns an input event with [`Received::NoInput`] if there's no
+    /// complete event, like while a paste is still being received.
+    ///
+    /// [`poll_event`]: Notcurses#method.poll_event
+    /// [`get_extended_event`]: Notcurses#method.get_extended_event
+    /// [`Received::NoInput`]: crate::Received#variant.NoInput
+    pub fn poll_extended_event(&mut self) -> Result<Event> {
+        loop {
+            if let Some(event) = self.events.pop() {
+                return Ok(event);
+            }
+            let input = if self.events.is_waiting() {
+                self.poll_event_timeout(ESCAPE_DELAY)?
+            } else {
+                self.poll_event()?
+            };
+            if input.received() {
+                self.events.feed(input);
+            } else {
+                self.events.flush();
+                return Ok(self.events.pop().unwrap_or(Event::Input(input)));
+            }
+        }
+    }
+
+    /// Pushes a synthetic `input` to the end of the queue of injected inputs.
+    ///
+    /// Injected inputs are returned in order by [`get_event`], [`poll_event`],
+    /// [`poll_event_timeout`] and the [`EventStream`], before any input from
+    /// the terminal. This allows testing the input handling without a terminal.
+    ///
+    /// Note that they don't make the [`input_fd`] readable, so they don't wake up
+    /// an external event loop waiting on it, nor a pending [`EventStream`], which
+    /// only returns them once it's polled again, or once some terminal input arrives.
+    ///
+    /// [`get_event`]: Notcurses#method.get_event
+    /// [`poll_event`]: Notcurses#method.poll_event
+    /// [`poll_event_timeout`]: Notcurses#method.poll_event_timeout
+    /// [`EventStream`]: crate::EventStream
+    /// [`input_fd`]: Notcurses#method.input_fd
+    ///
+    /// # Example
+    /// ```
+    /// # use notcurses::*;
+    /// # fn main() -> NotcursesResult<()> {
+    /// let mut nc = Notcurses::headless((80, 24))?;
+    /// nc.inject_inputs([
+    ///     Input::from(Key::Up),
+    ///     Input::from('q'),
+    ///     Input::mouse(Key::Button1, InputType::Press, (3, 4)),
+    /// ]);
+    /// assert![nc.get_event()?.is_key(Key::Up)];
+    /// assert![nc.get_event()?.is_char('q')];
+    /// assert_eq![Some(Position::new(3, 4)), nc.get_event()?.cell];
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn inject_input(&mut self, input: impl Into<Input>) {
+        self.injected.push_back(input.into());
+    }
+
+    /// Pushes several synthetic `inputs` to the end of the queue of injected inputs.
+    ///
+    /// See [`inject_input`][Notcurses#method.inject_input].
+    pub fn inject_inputs<I: Into<Input>>(&mut self, inputs: impl IntoIterator<Item = I>) {
+        self.injected.extend(inputs.into_iter().map(Into::into));
+    }
+
+    /// Returns the number of injected inputs not yet returned.
+    pub fn injected_inputs(&self) -> usize {
+        self.injected.len()
+    }
+
+    /// Discards the injected inputs not yet returned.
+    pub fn clear_injected_inputs(&mut self) {
+        self.injected.clear();
+    }
+
+    /// Starts recording the session into `writer`, in the asciicast v2 format.
+    ///
+    /// Every [`Input`] returned by [`get_event`], [`poll_event`] and
+    /// [`poll_event_timeout`] is recorded, as well as every frame written out
+    /// by [`Plane.render`] and [`Plane.rasterize`], with the time elapsed since
+    /// the start of the recording.
+    ///
+    /// Any previous recording is stopped first. The recording is stopped
+    /// when calling [`stop_recording`], or when this instance is dropped.
+    ///
+    /// Recording is best-effort: if writing to the `writer` fails, the
+    /// recording is stopped early, without failing the input or rendering
+    /// that was being recorded, and the error is returned by [`stop_recording`].
+    ///
+    /// The session can be played back with an asciicast player,
+    /// and its inputs can be fed back to the app with a [`Replayer`].
+    ///
+    /// [`get_event`]: Notcurses#method.get_event
+    /// [`poll_event`]: Notcurses#method.poll_event
+    /// [`poll_event_timeout`]: Notcurses#method.poll_event_timeout
+    /// [`Plane.render`]: crate::Plane#method.render
+    /// [`Plane.rasterize`]: crate::Plane#method.rasterize
+    /// [`stop_recording`]: Notcurses#method.stop_recording
+    ///
+    /// # Errors
+    /// - if the previous recording failed, as in [`stop_recording`].
+    /// - if the header can't be written.
+    ///
+    /// # Example
+    /// ```ignore
+    /// # use notcurses::*;
+    /// # use std::{fs::File, io::BufWriter};
+    /// # fn main() -> NotcursesResult<()> {
+    /// let mut nc = Notcurses::new()?;
+    /// nc.start_recording(BufWriter::new(File::create("session.cast")?))?;
+    /// // …
+    /// nc.stop_recording()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_recording(&mut self, writer: impl Write + 'static) -> Result<()> {
+        let (rows, cols) = self.into_ref().term_dim_yx();
+        session::start_recording(Box::new(writer), Size::new(cols as i32, rows as i32))
+    }
+
+    /// Stops recording the session, flushing the writer.
+    ///
+    /// Does nothing if the session is not being recorded.
+    ///
+    /// # Errors
+    /// - if flushing the writer fails.
+    /// - if writing to it failed before, which stopped the recording early.
+    pub fn stop_recording(&mut self) -> Result<()> {
+        session::stop_recording()
+    }
+
+    /// Returns `true` if the session is being recorded.
+    pub fn is_recording(&self) -> bool {
+        session::is_recording()
+    }
+
+    /// [Injects][Notcurses#method.inject_input] all the inputs of the `replayer`
+    /// at once, to be returned as fast as they are requested.
+    pub fn replay(&mut self, replayer: &Replayer) {
+        self.inject_inputs(replayer.inputs().iter().map(|(_, input)| *input));
+    }
+
+    /// Schedules the inputs of the `replayer` to be returned at the same
+    /// times they were recorded, relative to now.
+    ///
+    /// Scheduled inputs are returned after any injected input, and before any
+    /// input from the terminal. [`get_event`] waits for the next scheduled
+    /// input, while [`poll_event_timeout`] only waits for it if it's due
+    /// before the `timeout` expires.
+    ///
+    /// Any inputs still scheduled from a previous replay are discarded.
+    ///
+    /// [`get_event`]: Notcurses#method.get_event
+    /// [`poll_event_timeout`]: Notcurses#method.poll_event_timeout
+    pub fn replay_timed(&mut self, replayer: &Replayer) {
+        let start = Instant::now();
+        self.replaying = replayer
+            .inputs()
+            .iter()
+            .map(|(time, input)| (start + *time, *input))
+            .collect();
+    }
+
+    /// Returns the number of replayed inputs not yet returned.
+    pub fn replaying_inputs(&self) -> usize {
+        self.replaying.len()
+    }
+
+    /// Returns a file descriptor suitable for input event polling.
+    ///
+    /// When this descriptor becomes readable, you can call
+    /// [`poll_event`][Notcurses#method.poll_event], and input ought be ready.
+    ///
+    /// This allows integrating *Notcurses* input with external event loops.
+    ///
+    /// The returned descriptor doesn't borrow `self`, so it can be kept
+    /// registered while the events are polled.
+    pub fn input_fd(&self) -> Result<InputFd> {
+        // the C function doesn't modify the notcurses context.
+        let fd = unsafe { c_api::notcurses_inputready_fd(self.nc) };
+        if fd < c_api::NCRESULT_OK {
+            return Err(NcError::with_msg(fd, "Notcurses.input_fd()").into());
+        }
+        Ok(InputFd::new(fd))
+    }
+
+    /// Returns an asynchronous stream of input events.
+    ///
+    /// # Errors
+    /// - if it's called outside of a *tokio* runtime.
+    ///
+    /// # Panics
+    /// - if the *tokio* runtime doesn't have IO enabled.
+    #[cfg(all(feature = "tokio", unix))]
+    pub fn event_stream(&mut self) -> Result<super::EventStream<'_>> {
+        super::EventStream::new(self)
+    }
 }
 
 /// # general query methods
@@ -247,6 +692,15 @@ impl Notcurses {
         Size::from(self.into_ref().term_dim_yx()).swapped()
     }
 
+    /// Returns the topmost plane of the *cli* plane's pile that is visible at
+    /// the `position`, and the `position` translated to that plane.
+    ///
+    /// See [`Plane.hit_test`][crate::Plane#method.hit_test] for other piles.
+    pub fn plane_at(&mut self, position: impl Into<Position>) -> Option<(PlaneId, Position)> {
+        let stdplane = unsafe { self.into_ref_mut().stdplane() };
+        plane::pile_hit_test(stdplane, position.into())
+    }
+
     /// Returns the terminal geometry with the best resolution blitter available,
     /// using the following rules of *graceful degradation*:
     ///
@@ -0,0 +1,82 @@
+// notcurses::plane::box_channels
+//
+//!
+//
+
+use crate::color::Channels;
+
+/// The [`Channels`] of the four corners of a box.
+///
+/// Each edge is drawn with a gradient between the channels of the corners it
+/// connects, or with solid colors when both corners have the same channels.
+///
+/// It can be converted from a single `Channels`, for a box with solid colors,
+/// or from an array of channels in the order of [`gradient`][Self::gradient].
+///
+/// # Used by
+/// - [`Plane.draw_box`][crate::Plane#method.draw_box]
+/// - [`Plane.perimeter`][crate::Plane#method.perimeter]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BoxChannels {
+    /// The channels of the upper-left corner.
+    pub ul: Channels,
+    /// The channels of the upper-right corner.
+    pub ur: Channels,
+    /// The channels of the lower-left corner.
+    pub ll: Channels,
+    /// The channels of the lower-right corner.
+    pub lr: Channels,
+}
+
+mod core_impls {
+    use super::{BoxChannels, Channels};
+
+    impl From<Channels> for BoxChannels {
+        fn from(channels: Channels) -> BoxChannels {
+            BoxChannels::solid(channels)
+        }
+    }
+    impl From<[Channels; 4]> for BoxChannels {
+        fn from([ul, ur, ll, lr]: [Channels; 4]) -> BoxChannels {
+            BoxChannels::gradient(ul, ur, ll, lr)
+        }
+    }
+}
+
+/// # constructors
+impl BoxChannels {
+    /// Returns the same `channels` for all the corners.
+    pub fn solid(channels: impl Into<Channels>) -> BoxChannels {
+        let channels = channels.into();
+        Self {
+            ul: channels,
+            ur: channels,
+            ll: channels,
+            lr: channels,
+        }
+    }
+
+    /// Returns new channels for each corner: upper-left, upper-right,
+    /// lower-left and lower-right.
+    pub fn gradient(
+        ul: impl Into<Channels>,
+        ur: impl Into<Channels>,
+        ll: impl Into<Channels>,
+        lr: impl Into<Channels>,
+    ) -> BoxChannels {
+        Self {
+            ul: ul.into(),
+            ur: ur.into(),
+            ll: ll.into(),
+            lr: lr.into(),
+        }
+    }
+}
+
+/// # methods
+impl BoxChannels {
+    /// Returns `true` if all the corners have the same channels.
+    pub fn is_solid(&self) -> bool {
+        self.ul == self.ur && self.ul == self.ll && self.ul == self.lr
+    }
+}
@@ -0,0 +1,78 @@
+// notcurses::plane::box_edges
+//
+//!
+//
+
+use crate::sys::c_api::{
+    NcBoxMask_u32, NCBOXMASK_BOTTOM, NCBOXMASK_LEFT, NCBOXMASK_RIGHT, NCBOXMASK_TOP,
+};
+
+/// A bitmask of the edges of a box that are drawn.
+///
+/// The corners are drawn regardless of the edges.
+///
+/// # Used by
+/// - [`Plane.draw_box`][crate::Plane#method.draw_box]
+/// - [`Plane.perimeter`][crate::Plane#method.perimeter]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoxEdges(NcBoxMask_u32);
+
+/// # Flags
+#[allow(non_upper_case_globals)]
+impl BoxEdges {
+    /// The top edge.
+    pub const Top: Self = Self(NCBOXMASK_TOP);
+
+    /// The right edge.
+    pub const Right: Self = Self(NCBOXMASK_RIGHT);
+
+    /// The bottom edge.
+    pub const Bottom: Self = Self(NCBOXMASK_BOTTOM);
+
+    /// The left edge.
+    pub const Left: Self = Self(NCBOXMASK_LEFT);
+
+    /// None of the edges.
+    pub const None: Self = Self(0);
+
+    /// All the edges.
+    pub const All: Self = Self(NCBOXMASK_TOP | NCBOXMASK_RIGHT | NCBOXMASK_BOTTOM | NCBOXMASK_LEFT);
+}
+
+mod core_impls {
+    use super::{BoxEdges, NcBoxMask_u32};
+
+    impl Default for BoxEdges {
+        fn default() -> Self {
+            Self::All
+        }
+    }
+
+    crate::from_primitive![BoxEdges, NcBoxMask_u32];
+    crate::unit_impl_ops![bitwise; BoxEdges, NcBoxMask_u32];
+    crate::unit_impl_fmt![bases; BoxEdges];
+}
+
+/// # methods
+impl BoxEdges {
+    /// Returns `true` if the current edges include all the `other` edges.
+    pub fn has(&self, other: impl Into<BoxEdges>) -> bool {
+        let other = other.into();
+        (self.0 & other.0) == other.0
+    }
+
+    /// Adds the `other` edges to the current edges.
+    pub fn set(&mut self, other: impl Into<BoxEdges>) {
+        self.0 |= other.into().0
+    }
+
+    /// Removes the `other` edges from the current edges.
+    pub fn unset(&mut self, other: impl Into<BoxEdges>) {
+        self.0 &= !other.into().0
+    }
+
+    /// Returns the notcurses mask of the edges that are *not* drawn.
+    pub(crate) fn to_mask(self) -> NcBoxMask_u32 {
+        !self.0 & BoxEdges::All.0
+    }
+}
@@ -0,0 +1,68 @@
+// notcurses::plane::box_style
+//
+//!
+//
+
+/// The set of characters used to draw a box.
+///
+/// # Used by
+/// - [`Plane.draw_box`][crate::Plane#method.draw_box]
+/// - [`Plane.perimeter`][crate::Plane#method.perimeter]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BoxStyle<'a> {
+    /// Light lines: `┌┐└┘─│`.
+    ///
+    /// This is the default style.
+    #[default]
+    Light,
+
+    /// Heavy lines: `┏┓┗┛━┃`.
+    Heavy,
+
+    /// Light lines with rounded corners: `╭╮╰╯─│`.
+    Rounded,
+
+    /// Double lines: `╔╗╚╝═║`.
+    Double,
+
+    /// ASCII characters: `/\\/-|`.
+    Ascii,
+
+    /// Custom grapheme clusters, in this order: upper-left corner,
+    /// upper-right corner, lower-left corner, lower-right corner,
+    /// horizontal line and vertical line.
+    Custom([&'a str; 6]),
+}
+
+mod core_impls {
+    use super::BoxStyle;
+    use core::fmt;
+
+    impl fmt::Display for BoxStyle<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.egcs().concat())
+        }
+    }
+
+    impl<'a> From<[&'a str; 6]> for BoxStyle<'a> {
+        fn from(egcs: [&'a str; 6]) -> BoxStyle<'a> {
+            BoxStyle::Custom(egcs)
+        }
+    }
+}
+
+/// # methods
+impl<'a> BoxStyle<'a> {
+    /// Returns the grapheme clusters of the box, in this order:
+    /// `[upper-left, upper-right, lower-left, lower-right, horizontal, vertical]`.
+    pub const fn egcs(&self) -> [&'a str; 6] {
+        match self {
+            BoxStyle::Light => ["┌", "┐", "└", "┘", "─", "│"],
+            BoxStyle::Heavy => ["┏", "┓", "┗", "┛", "━", "┃"],
+            BoxStyle::Rounded => ["╭", "╮", "╰", "╯", "─", "│"],
+            BoxStyle::Double => ["╔", "╗", "╚", "╝", "═", "║"],
+            BoxStyle::Ascii => ["/", "\\", "\\", "/", "-", "|"],
+            BoxStyle::Custom(egcs) => *egcs,
+        }
+    }
+}
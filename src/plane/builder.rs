@@ -5,15 +5,30 @@
 
 use crate::{
     error::NotcursesResult as Result,
-    plane::{Align, Plane},
+    plane::{resize, Align, Plane, ResizeCallback},
     sys::{NcPlane, NcPlaneOptionsBuilder},
     Notcurses, Position, Size,
 };
 
 /// A [`Plane`] builder.
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct PlaneBuilder {
     options: NcPlaneOptionsBuilder,
+    resize_cb: Option<ResizeCallback>,
+}
+
+mod core_impls {
+    use super::PlaneBuilder;
+    use core::fmt;
+
+    impl fmt::Debug for PlaneBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PlaneBuilder")
+                .field("options", &self.options)
+                .field("resize_cb", &self.resize_cb.is_some())
+                .finish()
+        }
+    }
 }
 
 /// # Constructors
@@ -29,12 +44,18 @@ impl PlaneBuilder {
     /// Returns a new standalone `Plane`.
     pub fn build(self, nc: &mut Notcurses) -> Result<Plane> {
         let ncplane = NcPlane::new_pile(nc.into_ref_mut(), &self.options.build())?;
+        if let Some(cb) = self.resize_cb {
+            resize::set(ncplane, cb);
+        }
         Ok(Plane { nc: ncplane })
     }
 
     /// Returns a new child `Plane` of the provided parent.
     pub fn build_child(self, parent: &mut Plane) -> Result<Plane> {
         let ncplane = NcPlane::new_child(parent.into_ref_mut(), &self.options.build())?;
+        if let Some(cb) = self.resize_cb {
+            resize::set(ncplane, cb);
+        }
         Ok(Plane { nc: ncplane })
     }
 }
@@ -198,5 +219,15 @@ impl PlaneBuilder {
         self
     }
 
-    // TODO: resizecb
+    /// Sets a `callback` to be called when the parent plane is resized.
+    ///
+    /// Default: *`None`*.
+    ///
+    /// Effect: sets the plane's resize callback.
+    ///
+    /// See also: [`Plane.set_resize_cb`][super::Plane#method.set_resize_cb].
+    pub fn resize_cb(mut self, callback: impl FnMut(&mut Plane) -> Result<()> + 'static) -> Self {
+        self.resize_cb = Some(Box::new(callback));
+        self
+    }
 }
@@ -0,0 +1,273 @@
+// notcurses::plane::fade
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{c_api, Nc, NcFadeCtx, NcPlane, NcTime},
+};
+use core::{
+    any::Any,
+    ffi::{c_int, c_void},
+    mem::ManuallyDrop,
+    ops::ControlFlow,
+    ptr::null_mut,
+    time::Duration,
+};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+/// A stepwise fade of a [`Plane`], that can be advanced from an event loop.
+///
+/// Unlike [`Plane.fade_in`] and [`Plane.fade_out`], it doesn't block.
+/// Each call to [`advance`] updates the colors of the plane one iteration
+/// further, and renders it.
+///
+/// The plane recovers its original colors when fading in, so it must be
+/// drawn before creating a fade-in context, but not rendered.
+///
+/// [`Plane.fade_in`]: Plane#method.fade_in
+/// [`Plane.fade_out`]: Plane#method.fade_out
+/// [`advance`]: FadeContext#method.advance
+///
+/// # Example
+/// ```ignore
+/// # use notcurses::*;
+/// # use std::time::Duration;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::new()?;
+/// # let mut plane = Plane::new(&mut nc)?;
+/// putstr!(plane, "splash screen")?;
+/// let mut fade = FadeContext::fade_in(&mut plane)?;
+/// let frame = fade.frame_duration(Duration::from_secs(1));
+/// while fade.advance()? {
+///     let input = nc.poll_event_timeout(frame)?;
+///     // …
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct FadeContext<'p> {
+    nc: *mut NcFadeCtx,
+    plane: &'p mut Plane,
+    fade_in: bool,
+    iteration: u32,
+    iterations: u32,
+}
+
+mod core_impls {
+    use super::FadeContext;
+    use core::fmt;
+
+    impl Drop for FadeContext<'_> {
+        fn drop(&mut self) {
+            if crate::Notcurses::is_initialized() {
+                unsafe { crate::sys::c_api::ncfadectx_free(self.nc) };
+            }
+        }
+    }
+
+    impl fmt::Debug for FadeContext<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "FadeContext {{ fade_in: {}, iteration: {}/{} }}",
+                self.fade_in, self.iteration, self.iterations
+            )
+        }
+    }
+}
+
+/// # constructors
+impl<'p> FadeContext<'p> {
+    /// Returns a new context for fading in the `plane`, from black to its
+    /// current colors.
+    ///
+    /// # Errors
+    /// - if the terminal can't fade, with [`NotcursesError::Unsupported`].
+    ///
+    /// [`NotcursesError::Unsupported`]: crate::NotcursesError::Unsupported
+    pub fn fade_in(plane: &'p mut Plane) -> Result<FadeContext<'p>> {
+        Self::new(plane, true)
+    }
+
+    /// Returns a new context for fading out the `plane`, from its current
+    /// colors to black.
+    ///
+    /// # Errors
+    /// - if the terminal can't fade, with [`NotcursesError::Unsupported`].
+    ///
+    /// [`NotcursesError::Unsupported`]: crate::NotcursesError::Unsupported
+    pub fn fade_out(plane: &'p mut Plane) -> Result<FadeContext<'p>> {
+        Self::new(plane, false)
+    }
+
+    fn new(plane: &'p mut Plane, fade_in: bool) -> Result<FadeContext<'p>> {
+        check_support(plane)?;
+        let nc = unsafe { c_api::ncfadectx_setup(plane.nc) };
+        if nc.is_null() {
+            return Error::msg("Couldn't create the `FadeContext`.");
+        }
+        let iterations = unsafe { c_api::ncfadectx_iterations(nc) }.max(1) as u32;
+        Ok(Self {
+            nc,
+            plane,
+            fade_in,
+            iteration: 0,
+            iterations,
+        })
+    }
+}
+
+/// # methods
+impl FadeContext<'_> {
+    /// Returns `true` if it's fading in, or `false` if it's fading out.
+    pub fn is_fade_in(&self) -> bool {
+        self.fade_in
+    }
+
+    /// Returns the number of iterations already done.
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    /// Returns the total number of iterations of the fade.
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Returns `true` if all the iterations have been done.
+    pub fn is_done(&self) -> bool {
+        self.iteration >= self.iterations
+    }
+
+    /// Returns the progress of the fade, between `0.0` and `1.0`.
+    pub fn progress(&self) -> f64 {
+        self.iteration as f64 / self.iterations as f64
+    }
+
+    /// Returns how long each iteration has to last for the whole fade to
+    /// last the `total` duration.
+    pub fn frame_duration(&self, total: Duration) -> Duration {
+        total / self.iterations
+    }
+
+    /// Returns a shared reference to the fading plane.
+    pub fn plane(&self) -> &Plane {
+        self.plane
+    }
+
+    /// Does the next iteration of the fade, and renders the plane.
+    ///
+    /// Returns `true` if there are iterations left, or `false` once it's done.
+    pub fn advance(&mut self) -> Result<bool> {
+        if !self.is_done() {
+            self.set_iteration(self.iteration + 1)?;
+        }
+        Ok(!self.is_done())
+    }
+
+    /// Jumps to the last iteration of the fade, and renders the plane.
+    pub fn finish(&mut self) -> Result<()> {
+        self.set_iteration(self.iterations)
+    }
+
+    // Sets the colors of the plane for the `iteration`, and renders it.
+    fn set_iteration(&mut self, iteration: u32) -> Result<()> {
+        let res = unsafe {
+            if self.fade_in {
+                c_api::ncplane_fadein_iteration(
+                    self.plane.nc,
+                    self.nc,
+                    iteration as c_int,
+                    None,
+                    null_mut(),
+                )
+            } else {
+                c_api::ncplane_fadeout_iteration(
+                    self.plane.nc,
+                    self.nc,
+                    iteration as c_int,
+                    None,
+                    null_mut(),
+                )
+            }
+        };
+        self.iteration = iteration;
+        if res == c_api::NCRESULT_OK {
+            Ok(())
+        } else {
+            Error::msg(&format!["Couldn't do the fade iteration {iteration}."])
+        }
+    }
+}
+
+// Returns an `Unsupported` error if the terminal can't fade.
+pub(super) fn check_support(plane: &Plane) -> Result<()> {
+    if plane.capabilities().fade {
+        Ok(())
+    } else {
+        Err(Error::Unsupported(
+            "The terminal can't change neither the colors nor the palette.".into(),
+        ))
+    }
+}
+
+// Converts a duration into the notcurses time type.
+pub(super) fn nctime(duration: Duration) -> NcTime {
+    NcTime::new(duration.as_secs() as _, duration.subsec_nanos() as _)
+}
+
+// The value returned to notcurses to stop pulsing.
+pub(super) const PULSE_BREAK: c_int = 1;
+
+// The state shared with the pulse trampoline.
+pub(super) struct Pulse<'a> {
+    pub(super) callback: &'a mut dyn FnMut(u32) -> ControlFlow<()>,
+    pub(super) iteration: u32,
+    pub(super) error: Option<Error>,
+    pub(super) panic: Option<Box<dyn Any + Send>>,
+}
+
+impl Pulse<'_> {
+    // Propagates the outcome of pulsing, once notcurses has returned `res`.
+    pub(super) fn finish(self, res: c_int) -> Result<()> {
+        if let Some(panic) = self.panic {
+            resume_unwind(panic);
+        }
+        match (self.error, res) {
+            (Some(error), _) => Err(error),
+            (None, c_api::NCRESULT_OK | PULSE_BREAK) => Ok(()),
+            (None, _) => Error::msg("Couldn't pulse the plane."),
+        }
+    }
+}
+
+// The C fader that renders the plane and calls the pulse callback.
+pub(super) unsafe extern "C" fn pulse_trampoline(
+    _nc: *mut Nc,
+    ncplane: *mut NcPlane,
+    _time: *const NcTime,
+    curry: *mut c_void,
+) -> c_int {
+    let pulse = &mut *(curry as *mut Pulse);
+
+    // the plane is owned elsewhere, so it must not be destroyed here.
+    let mut plane = ManuallyDrop::new(Plane { nc: ncplane });
+    if let Err(error) = plane.render() {
+        pulse.error = Some(error);
+        return c_api::NCRESULT_ERR;
+    }
+
+    let iteration = pulse.iteration;
+    pulse.iteration += 1;
+    match catch_unwind(AssertUnwindSafe(|| (pulse.callback)(iteration))) {
+        Ok(ControlFlow::Continue(())) => c_api::NCRESULT_OK,
+        Ok(ControlFlow::Break(())) => PULSE_BREAK,
+        Err(panic) => {
+            pulse.panic = Some(panic);
+            c_api::NCRESULT_ERR
+        }
+    }
+}
@@ -29,15 +29,31 @@
 //! [`new_cli`]: [Plane#new_cli],
 
 mod align;
+mod box_channels;
+mod box_edges;
+mod box_style;
 mod builder;
 mod cell;
+mod fade;
 mod geometry;
 mod plane;
+mod plane_id;
+mod resize;
+mod snapshot;
 mod style;
 
 pub use align::Align;
+pub use box_channels::BoxChannels;
+pub use box_edges::BoxEdges;
+pub use box_style::BoxStyle;
 pub use builder::PlaneBuilder;
 pub use cell::Cell;
+pub use fade::FadeContext;
 pub use geometry::PlaneGeometry;
+pub(crate) use plane::pile_hit_test;
 pub use plane::Plane;
+pub use plane_id::PlaneId;
+pub(crate) use resize::forget_all as forget_resize_callbacks;
+pub use resize::ResizeCallback;
+pub use snapshot::{PlaneSnapshot, SnapshotCell, UPDATE_SNAPSHOTS_VAR};
 pub use style::Style;
@@ -5,13 +5,21 @@
 
 use crate::{
     color::{Channel, Channels},
-    error::NotcursesResult as Result,
+    error::{NotcursesError as Error, NotcursesResult as Result},
     notcurses::{Capabilities, Notcurses},
-    plane::{Align, Cell, PlaneBuilder, PlaneGeometry, Style},
-    sys::NcPlane,
+    plane::{
+        fade, resize, Align, BoxChannels, BoxEdges, BoxStyle, Cell, PlaneBuilder, PlaneGeometry,
+        PlaneId, PlaneSnapshot, Style,
+    },
+    sys::{
+        c_api::{self, libc::c_void},
+        NcAlpha, NcCell, NcChannel, NcChannels, NcError, NcPlane,
+    },
     visual::Blitter,
     Position, Size,
 };
+use core::{ffi::c_char, ops::ControlFlow, ptr::null_mut, slice, time::Duration};
+use std::{ffi::CString, io::Write};
 
 /// A drawable text surface, composed of [`Cell`]s.
 pub struct Plane {
@@ -34,6 +42,7 @@ mod core_impls {
             } else {
                 // Only destroy it if it's not the CLI plane.
                 if crate::Notcurses::is_initialized() {
+                    super::resize::forget(self.nc);
                     let _res = self.into_ref_mut().destroy();
                 }
             }
@@ -230,7 +239,9 @@ impl Plane {
     /// Renders and rasterizes the pile of which this `Plane` is part.
     #[inline]
     pub fn render(&mut self) -> Result<()> {
-        Ok(self.into_ref_mut().render_raster()?)
+        self.into_ref_mut().render_raster()?;
+        self.record_frame();
+        Ok(())
     }
 
     /// Just renders the pile of which this `Plane` is part, without rasterizing.
@@ -250,36 +261,68 @@ impl Plane {
     /// rendered (doing so will likely result in a blank screen).
     #[inline]
     pub fn rasterize(&mut self) -> Result<()> {
-        Ok(self.into_ref_mut().rasterize()?)
+        self.into_ref_mut().rasterize()?;
+        self.record_frame();
+        Ok(())
     }
 
-    // TODO
-    // /// Performs the rendering and rasterization portion of
-    // /// [`render`][Plane#method.render]
-    // /// but does not write the resulting buffer out to the terminal.
-    // ///
-    // /// Using this function, the user can control the writeout process.
-    // /// The returned buffer must be freed by the caller.
-    // ///
-    // #[inline]
-    // pub fn render_to_buffer(&mut self, buffer: &mut Vec<u8>) -> Result<()> {
-    //     Ok(self.into_ref_mut().render_to_buffer(buffer)?)
-    // }
+    // Records the frame just written out, if the session is being recorded.
+    //
+    // It doesn't fail, since recording is best-effort.
+    fn record_frame(&mut self) {
+        if !crate::session::is_recording() {
+            return;
+        }
+        if let Ok(nc) = unsafe { self.into_ref_mut().notcurses() } {
+            crate::session::record_frame(nc);
+        }
+    }
 
-    // TODO
-    // /// Writes the last rendered frame, in its entirety, to `file`.
-    // ///
-    // #[inline]
-    // pub fn render_to_file(&mut self, file: &mut File) -> Result<()> {
-    //     Ok(self.into_ref_mut().render_to_file(file)?)
-    // }
+    /// Renders and rasterizes the pile of which this `Plane` is part,
+    /// returning the resulting buffer instead of writing it out to the terminal.
+    ///
+    /// Using this function, the user can control the writeout process.
+    #[inline]
+    pub fn render_to_buffer(&mut self) -> Result<Vec<u8>> {
+        self.render_only()?;
+
+        let mut buf: *mut c_char = null_mut();
+        let mut len: usize = 0;
+        let res =
+            unsafe { c_api::ncpile_render_to_buffer(self.into_ref_mut(), &mut buf, &mut len) };
+
+        let bytes = if buf.is_null() {
+            vec![]
+        } else {
+            // copy the C-allocated buffer before freeing it.
+            let bytes = unsafe { slice::from_raw_parts(buf as *const u8, len) }.to_vec();
+            unsafe { c_api::libc::free(buf as *mut c_void) };
+            bytes
+        };
+
+        if res < c_api::NCRESULT_OK {
+            return Err(NcError::with_msg(res, "Plane.render_to_buffer()").into());
+        }
+        Ok(bytes)
+    }
+
+    /// Renders and rasterizes the pile of which this `Plane` is part,
+    /// writing the resulting buffer out to `writer` instead of to the terminal.
+    ///
+    /// Returns the number of bytes written.
+    #[inline]
+    pub fn render_to_writer<W: Write>(&mut self, writer: &mut W) -> Result<usize> {
+        let bytes = self.render_to_buffer()?;
+        writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
 }
 
 /// # size, geometry
 impl Plane {
     // convenience function to get the capabilities directly from a Plane.
     #[inline]
-    fn capabilities(&self) -> Capabilities {
+    pub(super) fn capabilities(&self) -> Capabilities {
         let nc = unsafe { self.into_ref().notcurses_const() }.expect("notcurses_const");
 
         Capabilities {
@@ -358,44 +401,75 @@ impl Plane {
         Ok(self.into_ref_mut().resize_simple(h, w)?)
     }
 
-    // TODO CHECK callbacks
+    /// Realigns this plane against its parent, using the alignment specified
+    /// at creation time.
+    ///
+    /// Suitable for use as a [`ResizeCallback`][crate::ResizeCallback].
+    #[inline]
+    pub fn resize_realign(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().resize_realign()?)
+    }
 
-    // /// Realigns this plane against its parent, using the alignment specified
-    // /// at creation time.
-    // ///
-    // /// Suitable for use as a [`ResizeCallback`].
-    // #[inline]
-    // pub fn resize_realign(&mut self) -> Result<()> {
-    //     Ok(self.into_ref_mut().resize_realign()?)
-    // }
+    /// Resizes this plane against its parent, attempting to enforce
+    /// the supplied margins.
+    ///
+    /// Suitable for use as a [`ResizeCallback`][crate::ResizeCallback] on planes
+    /// created with [`maximize`][PlaneBuilder#method.maximize].
+    #[inline]
+    pub fn resize_maximize(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().resize_marginalized()?)
+    }
 
-    // /// Resizes this plane against its parent, attempting to enforce
-    // /// the supplied margins.
-    // ///
-    // /// This is suitable for use as a [`ResizeCallback`] on planes created
-    // /// with [`maximize`][PlaneBuilder#method.maximize].
-    // #[inline]
-    // pub fn resize_maximize(&mut self) -> Result<()> {
-    //     Ok(self.into_ref_mut().resize_maximize()?)
-    // }
+    /// Resizes this plane to the visual area's size.
+    ///
+    /// Suitable for use as a [`ResizeCallback`][crate::ResizeCallback].
+    #[inline]
+    pub fn resize_maximize_visual(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().resize_maximize()?)
+    }
 
-    // /// Resizes this plane to the visual area's size.
-    // #[inline]
-    // pub fn resize_maximize_visual(&mut self) -> Result<()> {
-    //     Ok(self.into_ref_mut().resize_maximize()?)
-    // }
+    /// Returns `true` if this plane has a resize callback set.
+    #[inline]
+    pub fn has_resize_cb(&self) -> bool {
+        resize::is_set(self.nc)
+    }
 
-    // /// Returns this plane's current resize callback, or `None` if not set.
-    // #[inline]
-    // pub fn resize_cb(&self) -> Option<ResizeCb> {
-    //     self.into_ref().resizecb()
-    // }
+    /// Sets the `callback` to be called when this plane's parent is resized,
+    /// replacing any previous one.
+    ///
+    /// # Errors
+    /// - if this is the *CLI* plane, since its callback may not be changed.
+    ///
+    /// # Example
+    /// ```
+    /// # use notcurses::*;
+    /// # fn main() -> NotcursesResult<()> {
+    /// # let mut nc = Notcurses::headless((80, 24))?;
+    /// # let mut parent = Plane::new(&mut nc)?;
+    /// let mut child = parent.new_child_sized((10, 4))?;
+    /// child.set_resize_cb(Plane::resize_realign)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_resize_cb(
+        &mut self,
+        callback: impl FnMut(&mut Plane) -> Result<()> + 'static,
+    ) -> Result<()> {
+        if self.is_cli() {
+            return Error::msg("The *CLI* `Plane`'s resize callback may not be changed.");
+        }
+        resize::set(self.nc, Box::new(callback));
+        Ok(())
+    }
 
-    // /// (Un)Sets this plane's resize callback.
-    // #[inline]
-    // pub fn set_resize_cb(&self, Option<ResizeCb>) {
-    //     self.into_ref_mut().set_resizecb()
-    // }
+    /// Unsets this plane's resize callback, if any.
+    #[inline]
+    pub fn unset_resize_cb(&mut self) {
+        if !self.is_cli() {
+            resize::unset(self.nc);
+        }
+    }
 }
 
 /// # area positioning
@@ -445,15 +519,13 @@ impl Plane {
     /// into a position relative to the `target` plane.
     ///
     /// # Example
-    /// ```ignore
+    /// ```
     /// # use notcurses::*;
     /// # fn main() -> NotcursesResult<()> {
-    /// # let nc = Notcurses::new()?;
-    ///     assert_eq![
-    ///         Plane::new(&mut nc)?
-    ///             .translate((0, 0), &Plane::new_at(&mut nc, (1, 0))?),
-    ///         Position::new(-1, 0),
-    ///     ];
+    /// # let mut nc = Notcurses::headless((80, 24))?;
+    /// let plane = Plane::new(&mut nc)?;
+    /// let target = Plane::new_at(&mut nc, (1, 0))?;
+    /// assert_eq![plane.translate((0, 0), &target), Position::new(-1, 0)];
     /// # Ok(())
     /// # }
     /// ```
@@ -472,10 +544,10 @@ impl Plane {
     /// - `.1`: Is *true* when `position` is inside this plane, or *false* otherwise.
     ///
     /// # Example
-    /// ```ignore
+    /// ```
     /// # use notcurses::*;
     /// # fn main() -> NotcursesResult<()> {
-    /// # let nc = Notcurses::new()?;
+    /// # let mut nc = Notcurses::headless((80, 24))?;
     /// assert_eq![
     ///     Plane::new_at(&mut nc, (8, 8))?.translate_root(Position::new(7, 7)),
     ///     (Position::new(-1, -1), false),
@@ -620,6 +692,56 @@ impl Plane {
     }
 }
 
+/// # hit testing
+impl Plane {
+    /// Returns the identifier of this plane.
+    #[inline]
+    pub fn id(&self) -> PlaneId {
+        PlaneId(self.nc)
+    }
+
+    /// Returns the topmost plane of this plane's pile that is visible at the
+    /// root `position`, and the `position` translated to that plane.
+    ///
+    /// The planes are walked from the top to the bottom of the pile, skipping
+    /// the planes that don't contain the `position`, or whose cell there is
+    /// fully transparent: without a glyph, nor a base cell glyph, and with
+    /// a transparent background.
+    ///
+    /// This is useful for routing mouse events, whose [`cell`] position is
+    /// relative to the root of the rendered pile.
+    ///
+    /// [`cell`]: crate::Input#structfield.cell
+    ///
+    /// # Example
+    /// ```
+    /// # use notcurses::*;
+    /// # fn main() -> NotcursesResult<()> {
+    /// # let mut nc = Notcurses::headless((80, 24))?;
+    /// let mut root = Plane::new(&mut nc)?;
+    /// let mut button = root.new_child_sized_at((10, 1), (4, 2))?;
+    /// button.set_base_bg(Channel::from_rgb_alpha(0x000000, Alpha::Transparent))?;
+    /// button.putstr("[OK]")?;
+    ///
+    /// // the position is translated to the topmost plane,
+    /// assert_eq![
+    ///     root.hit_test((6, 2)),
+    ///     Some((button.id(), Position::new(2, 0))),
+    /// ];
+    /// // skipping its transparent cells.
+    /// assert_eq![
+    ///     root.hit_test((10, 2)),
+    ///     Some((root.id(), Position::new(10, 2))),
+    /// ];
+    /// assert_eq![root.hit_test((80, 2)), None];
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn hit_test(&self, position: impl Into<Position>) -> Option<(PlaneId, Position)> {
+        pile_hit_test(self.nc, position.into())
+    }
+}
+
 /// # alignment, scrolling and growing
 impl Plane {
     /// Returns the column at which `width` columns ought start
@@ -844,7 +966,7 @@ impl Plane {
     /// ```
     /// # use notcurses::*;
     /// # fn main() -> NotcursesResult<()> {
-    /// # let mut nc = Notcurses::new_cli()?;
+    /// # let mut nc = Notcurses::headless((80, 24))?;
     /// # let mut plane = Plane::new(&mut nc)?;
     /// assert_eq![11, plane.putstr("hello world")?];
     /// # Ok(())
@@ -867,7 +989,7 @@ impl Plane {
     /// ```
     /// # use notcurses::*;
     /// # fn main() -> NotcursesResult<()> {
-    /// # let mut nc = Notcurses::new_cli()?;
+    /// # let mut nc = Notcurses::headless((80, 24))?;
     /// # let mut plane = Plane::new(&mut nc)?;
     /// plane.set_scrolling(true);
     /// assert_eq![12, plane.putstrln("hello world")?];
@@ -1064,6 +1186,471 @@ impl Plane {
         let _bytes = self.into_ref_mut().at_yx_cell(y, x, &mut cell)?;
         Ok(cell.into())
     }
+
+    /// Returns a snapshot of all the cells of the plane.
+    ///
+    /// See also the [`assert_plane_snapshot!`][crate::assert_plane_snapshot]
+    /// macro.
+    #[inline]
+    pub fn snapshot(&mut self) -> Result<PlaneSnapshot> {
+        PlaneSnapshot::new(self)
+    }
+}
+
+/// # boxes
+impl Plane {
+    /// Draws a box with its upper-left corner at `position`, of the given `size`.
+    ///
+    /// Only the selected `edges` are drawn, but the corners are always drawn.
+    /// The cursor is left at the lower-right corner.
+    ///
+    /// The `channels` can be different for each corner, in which case the
+    /// edges are drawn with a gradient between the corners they connect.
+    ///
+    /// # Errors
+    /// - if the `size` is smaller than 2×2, or the box doesn't fit in the plane.
+    /// - if any custom grapheme cluster is empty or contains a NUL character.
+    ///
+    /// # Example
+    /// ```
+    /// # use notcurses::*;
+    /// # fn main() -> NotcursesResult<()> {
+    /// # let mut nc = Notcurses::headless((80, 24))?;
+    /// # let mut plane = Plane::new_sized(&mut nc, (20, 10))?;
+    /// let red = Channels::from_rgb(0xFF0000, 0x000000);
+    /// let blue = Channels::from_rgb(0x0000FF, 0x000000);
+    /// plane.draw_box(
+    ///     (1, 1),
+    ///     (10, 4),
+    ///     BoxStyle::Rounded,
+    ///     BoxChannels::gradient(red, red, blue, blue),
+    ///     Style::Bold,
+    ///     BoxEdges::All,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn draw_box(
+        &mut self,
+        position: impl Into<Position>,
+        size: impl Into<Size>,
+        box_style: BoxStyle,
+        channels: impl Into<BoxChannels>,
+        styles: impl Into<Style>,
+        edges: impl Into<BoxEdges>,
+    ) -> Result<()> {
+        let (x, y): (u32, u32) = position.into().into();
+        let (w, h): (u32, u32) = size.into().into();
+        if w < 2 || h < 2 {
+            return Error::msg(&format!["A box can't be smaller than 2×2, found {w}×{h}."]);
+        }
+
+        let channels = channels.into();
+        let mut ctlword = edges.into().to_mask();
+        if !channels.is_solid() {
+            // edges connecting corners of the same channels stay solid.
+            ctlword |= c_api::NCBOXGRAD_TOP
+                | c_api::NCBOXGRAD_RIGHT
+                | c_api::NCBOXGRAD_BOTTOM
+                | c_api::NCBOXGRAD_LEFT;
+        }
+
+        // ul, ur, ll, lr, hline, vline
+        let cell_channels = [
+            channels.ul,
+            channels.ur,
+            channels.ll,
+            channels.lr,
+            channels.ul,
+            channels.ul,
+        ];
+        let stylemask: u16 = styles.into().into();
+        let mut cells = [NcCell::new(); 6];
+        for (i, egc) in box_style.egcs().iter().enumerate() {
+            cells[i].stylemask = stylemask;
+            cells[i].channels = NcChannels::from(cell_channels[i]).into();
+
+            let loaded = CString::new(*egc).map_or(-1, |egc| unsafe {
+                c_api::nccell_load(self.nc, &mut cells[i], egc.as_ptr())
+            });
+            if loaded <= 0 {
+                self.release_cells(&mut cells[..i]);
+                return Error::msg(&format!["Invalid box grapheme cluster {egc:?}."]);
+            }
+        }
+
+        let res = self.cursor_move_to((x, y)).and_then(|_| {
+            let res = unsafe {
+                c_api::ncplane_box(
+                    self.nc,
+                    &cells[0],
+                    &cells[1],
+                    &cells[2],
+                    &cells[3],
+                    &cells[4],
+                    &cells[5],
+                    y + h - 1,
+                    x + w - 1,
+                    ctlword,
+                )
+            };
+            if res < c_api::NCRESULT_OK {
+                Error::msg(&format!["Couldn't draw a {w}×{h} box at ({x}, {y})."])
+            } else {
+                Ok(())
+            }
+        });
+        self.release_cells(&mut cells);
+        res
+    }
+
+    /// Draws a box around the edges of the plane.
+    ///
+    /// See [`draw_box`][Plane#method.draw_box] for details.
+    ///
+    /// # Errors
+    /// - if the plane is smaller than 2×2.
+    /// - if any custom grapheme cluster is empty or contains a NUL character.
+    pub fn perimeter(
+        &mut self,
+        box_style: BoxStyle,
+        channels: impl Into<BoxChannels>,
+        styles: impl Into<Style>,
+        edges: impl Into<BoxEdges>,
+    ) -> Result<()> {
+        let size = self.size();
+        self.draw_box((0, 0), size, box_style, channels, styles, edges)
+    }
+
+    // Releases the resources the `cells` may hold in the plane's egc pool.
+    fn release_cells(&mut self, cells: &mut [NcCell]) {
+        for cell in cells {
+            unsafe { c_api::nccell_release(self.nc, cell) };
+        }
+    }
+}
+
+/// # lines and gradients
+impl Plane {
+    /// Draws a horizontal line of `len` copies of `cell`,
+    /// starting at the cursor position.
+    ///
+    /// The cursor is left at the cell following the last one drawn.
+    ///
+    /// Returns the number of cells drawn.
+    ///
+    /// # Errors
+    /// - if `len` is 0, or the line doesn't fit in the plane.
+    #[inline]
+    pub fn hline(&mut self, cell: &Cell, len: u32) -> Result<u32> {
+        let channels = cell.channels();
+        self.hline_gradient(cell, len, channels, channels)
+    }
+
+    /// Draws a horizontal line of `len` copies of `cell`,
+    /// starting at the cursor position, interpolating the channels
+    /// from `from` to `to`.
+    ///
+    /// The cursor is left at the cell following the last one drawn.
+    ///
+    /// Returns the number of cells drawn.
+    ///
+    /// # Errors
+    /// - if `len` is 0, or the line doesn't fit in the plane.
+    pub fn hline_gradient(
+        &mut self,
+        cell: &Cell,
+        len: u32,
+        from: impl Into<Channels>,
+        to: impl Into<Channels>,
+    ) -> Result<u32> {
+        let (from, to) = (NcChannels::from(from.into()), NcChannels::from(to.into()));
+        let res = unsafe {
+            let cell: &NcCell = cell.into();
+            c_api::ncplane_hline_interp(self.nc, cell, len, from.into(), to.into())
+        };
+        if res < 0 {
+            Error::msg(&format!["Couldn't draw a horizontal line of {len} cells."])
+        } else {
+            Ok(res as u32)
+        }
+    }
+
+    /// Draws a vertical line of `len` copies of `cell`,
+    /// starting at the cursor position.
+    ///
+    /// The cursor is left at the cell following the last one drawn,
+    /// to the right of it.
+    ///
+    /// Returns the number of cells drawn.
+    ///
+    /// # Errors
+    /// - if `len` is 0, or the line doesn't fit in the plane.
+    #[inline]
+    pub fn vline(&mut self, cell: &Cell, len: u32) -> Result<u32> {
+        let channels = cell.channels();
+        self.vline_gradient(cell, len, channels, channels)
+    }
+
+    /// Draws a vertical line of `len` copies of `cell`,
+    /// starting at the cursor position, interpolating the channels
+    /// from `from` to `to`.
+    ///
+    /// The cursor is left at the cell following the last one drawn,
+    /// to the right of it.
+    ///
+    /// Returns the number of cells drawn.
+    ///
+    /// # Errors
+    /// - if `len` is 0, or the line doesn't fit in the plane.
+    pub fn vline_gradient(
+        &mut self,
+        cell: &Cell,
+        len: u32,
+        from: impl Into<Channels>,
+        to: impl Into<Channels>,
+    ) -> Result<u32> {
+        let (from, to) = (NcChannels::from(from.into()), NcChannels::from(to.into()));
+        let res = unsafe {
+            let cell: &NcCell = cell.into();
+            c_api::ncplane_vline_interp(self.nc, cell, len, from.into(), to.into())
+        };
+        if res < 0 {
+            Error::msg(&format!["Couldn't draw a vertical line of {len} cells."])
+        } else {
+            Ok(res as u32)
+        }
+    }
+
+    /// Fills the `zone` with the `egc` and `styles`, blending the channels
+    /// of the four corners into foreground and background gradients.
+    ///
+    /// The `zone` is `(x, y, width, height)`. A width or height of 0 extends
+    /// to the right or bottom edge of the plane, and a position of -1 means
+    /// the cursor position in that dimension.
+    ///
+    /// - For a vertical gradient, `ul` must equal `ur`, and `ll` must equal `lr`.
+    /// - For a horizontal gradient, `ul` must equal `ll`, and `ur` must equal `lr`.
+    /// - For a solid fill, all four channels must be equal.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// # Errors
+    /// - if the zone falls outside the plane.
+    /// - if the channels use palette-indexed colors, or different alphas.
+    /// - if a zone of a single row or column has incompatible channels.
+    /// - if the `egc` contains a NUL character.
+    ///
+    /// # Example
+    /// ```
+    /// # use notcurses::*;
+    /// # fn main() -> NotcursesResult<()> {
+    /// # let mut nc = Notcurses::headless((80, 24))?;
+    /// # let mut plane = Plane::new_sized(&mut nc, (20, 10))?;
+    /// let top = Channels::from_rgb(0xFFFFFF, 0x000088);
+    /// let bottom = Channels::from_rgb(0xFFFFFF, 0x8800FF);
+    /// // a full-width band of 3 rows.
+    /// let filled = plane.gradient((0, 0, 0, 3), " ", Style::None, top, top, bottom, bottom)?;
+    /// assert_eq![20 * 3, filled];
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn gradient(
+        &mut self,
+        zone: (i32, i32, u32, u32),
+        egc: &str,
+        styles: impl Into<Style>,
+        ul: impl Into<Channels>,
+        ur: impl Into<Channels>,
+        ll: impl Into<Channels>,
+        lr: impl Into<Channels>,
+    ) -> Result<u32> {
+        let (x, y, w, h) = zone;
+        let egc = CString::new(egc)
+            .or_else(|_| Error::msg(&format!["Invalid gradient grapheme cluster {egc:?}."]))?;
+        let [ul, ur, ll, lr] = [ul.into(), ur.into(), ll.into(), lr.into()].map(NcChannels::from);
+        let res = unsafe {
+            c_api::ffi::ncplane_gradient(
+                self.nc,
+                y,
+                x,
+                h,
+                w,
+                egc.as_ptr(),
+                styles.into().into(),
+                ul.into(),
+                ur.into(),
+                ll.into(),
+                lr.into(),
+            )
+        };
+        if res < 0 {
+            Error::msg(&format![
+                "Couldn't draw a gradient at ({x}, {y}), size {w}×{h}."
+            ])
+        } else {
+            Ok(res as u32)
+        }
+    }
+
+    /// Fills the `zone` with a high-resolution gradient, blending the colors
+    /// of the four corners.
+    ///
+    /// It uses upper half blocks with synced backgrounds, which doubles the
+    /// number of vertical gradations, but overwrites the text of the zone.
+    ///
+    /// The `zone` is `(x, y, width, height)`. A width or height of 0 extends
+    /// to the right or bottom edge of the plane, and a position of -1 means
+    /// the cursor position in that dimension.
+    ///
+    /// Returns the number of cells filled.
+    ///
+    /// # Errors
+    /// - if the zone falls outside the plane.
+    /// - if the channels use palette-indexed colors, or different alphas.
+    pub fn gradient2x1(
+        &mut self,
+        zone: (i32, i32, u32, u32),
+        ul: impl Into<Channel>,
+        ur: impl Into<Channel>,
+        ll: impl Into<Channel>,
+        lr: impl Into<Channel>,
+    ) -> Result<u32> {
+        let (x, y, w, h) = zone;
+        let [ul, ur, ll, lr] = [ul.into(), ur.into(), ll.into(), lr.into()].map(NcChannel::from);
+        let res = unsafe {
+            c_api::ncplane_gradient2x1(
+                self.nc,
+                y,
+                x,
+                h,
+                w,
+                ul.into(),
+                ur.into(),
+                ll.into(),
+                lr.into(),
+            )
+        };
+        if res < 0 {
+            Error::msg(&format![
+                "Couldn't draw a gradient at ({x}, {y}), size {w}×{h}."
+            ])
+        } else {
+            Ok(res as u32)
+        }
+    }
+}
+
+/// # fading
+impl Plane {
+    /// Fades the plane out to black over the `duration`, rendering it at
+    /// each iteration.
+    ///
+    /// It blocks until the fade is done. See [`FadeContext`] for a
+    /// non-blocking alternative.
+    ///
+    /// # Errors
+    /// - if the terminal can't fade, with [`NotcursesError::Unsupported`],
+    ///   leaving the plane untouched.
+    ///
+    /// [`NotcursesError::Unsupported`]: crate::NotcursesError::Unsupported
+    pub fn fade_out(&mut self, duration: Duration) -> Result<()> {
+        fade::check_support(self)?;
+        let time = fade::nctime(duration);
+        let res = unsafe { c_api::ncplane_fadeout(self.nc, &time, None, null_mut()) };
+        if res == c_api::NCRESULT_OK {
+            Ok(())
+        } else {
+            Error::msg("Couldn't fade out the plane.")
+        }
+    }
+
+    /// Fades the plane in from black over the `duration`, rendering it at
+    /// each iteration.
+    ///
+    /// The plane must be drawn beforehand, but not rendered.
+    ///
+    /// It blocks until the fade is done. See [`FadeContext`] for a
+    /// non-blocking alternative.
+    ///
+    /// # Errors
+    /// - if the terminal can't fade, with [`NotcursesError::Unsupported`],
+    ///   after rendering the plane with its final colors.
+    ///
+    /// [`NotcursesError::Unsupported`]: crate::NotcursesError::Unsupported
+    pub fn fade_in(&mut self, duration: Duration) -> Result<()> {
+        if let Err(unsupported) = fade::check_support(self) {
+            self.render()?;
+            return Err(unsupported);
+        }
+        let time = fade::nctime(duration);
+        let res = unsafe { c_api::ncplane_fadein(self.nc, &time, None, null_mut()) };
+        if res == c_api::NCRESULT_OK {
+            Ok(())
+        } else {
+            Error::msg("Couldn't fade in the plane.")
+        }
+    }
+
+    /// Pulses the plane, fading it in and out repeatedly, with each
+    /// transition lasting the `half_period`.
+    ///
+    /// The plane is rendered at each iteration, and then the `callback` is
+    /// called with the number of iterations done so far. Pulsing goes on
+    /// until the callback returns [`ControlFlow::Break`].
+    ///
+    /// The plane must be drawn beforehand, but not rendered.
+    ///
+    /// # Errors
+    /// - if the terminal can't fade, with [`NotcursesError::Unsupported`],
+    ///   after rendering the plane with its final colors.
+    ///
+    /// [`NotcursesError::Unsupported`]: crate::NotcursesError::Unsupported
+    ///
+    /// # Example
+    /// ```
+    /// # use notcurses::*;
+    /// # use std::{ops::ControlFlow, time::Duration};
+    /// # fn main() -> NotcursesResult<()> {
+    /// # let mut nc = Notcurses::headless((80, 24))?;
+    /// # let mut plane = Plane::new(&mut nc)?;
+    /// putstr!(plane, "press any key")?;
+    /// let pulsing = plane.pulse(Duration::from_millis(50), |iteration| match nc.poll_event() {
+    ///     Ok(input) if !input.received() && iteration < 4 => ControlFlow::Continue(()),
+    ///     _ => ControlFlow::Break(()),
+    /// });
+    /// # if let Err(NotcursesError::Unsupported(_)) = pulsing { return Ok(()); }
+    /// pulsing?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pulse(
+        &mut self,
+        half_period: Duration,
+        mut callback: impl FnMut(u32) -> ControlFlow<()>,
+    ) -> Result<()> {
+        if let Err(unsupported) = fade::check_support(self) {
+            self.render()?;
+            return Err(unsupported);
+        }
+        let time = fade::nctime(half_period);
+        let mut pulse = fade::Pulse {
+            callback: &mut callback,
+            iteration: 0,
+            error: None,
+            panic: None,
+        };
+        let res = unsafe {
+            c_api::ncplane_pulse(
+                self.nc,
+                &time,
+                Some(fade::pulse_trampoline),
+                &mut pulse as *mut fade::Pulse as *mut c_void,
+            )
+        };
+        pulse.finish(res)
+    }
 }
 
 /// # colors, palette & styles
@@ -1271,3 +1858,44 @@ impl Plane {
         Ok(old_bg)
     }
 }
+
+// Returns the topmost plane of the pile of `plane` that is visible at the
+// root `position`, and the `position` translated to that plane.
+pub(crate) fn pile_hit_test(
+    plane: *mut NcPlane,
+    position: Position,
+) -> Option<(PlaneId, Position)> {
+    let (x, y): (i32, i32) = position.into();
+    let mut next = unsafe { c_api::ncpile_top(plane) };
+    while !next.is_null() {
+        let ncplane = unsafe { &mut *next };
+        let (mut px, mut py) = (x, y);
+        if ncplane.translate_abs(&mut py, &mut px)
+            && !is_transparent_at(ncplane, px as u32, py as u32)
+        {
+            return Some((PlaneId(next), Position::new(px, py)));
+        }
+        next = unsafe { c_api::ncplane_below(next) };
+    }
+    None
+}
+
+// Returns `true` if the cell of the `ncplane` at the `x`,`y` position would
+// be rendered fully transparent.
+fn is_transparent_at(ncplane: &mut NcPlane, x: u32, y: u32) -> bool {
+    let mut cell = NcCell::new();
+    let bytes = ncplane.at_yx_cell(y, x, &mut cell);
+    unsafe { c_api::nccell_release(ncplane, &mut cell) };
+    match bytes {
+        // an empty cell is rendered using the base cell.
+        Ok(0) => match ncplane.base() {
+            Ok(mut base) => {
+                let transparent = base.gcluster == 0 && base.bg_alpha() == NcAlpha::Transparent;
+                unsafe { c_api::nccell_release(ncplane, &mut base) };
+                transparent
+            }
+            Err(_) => false,
+        },
+        _ => false,
+    }
+}
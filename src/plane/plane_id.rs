@@ -0,0 +1,22 @@
+// notcurses::plane::plane_id
+//
+//!
+//
+
+use crate::sys::NcPlane;
+
+/// An identifier of a [`Plane`], that doesn't own it.
+///
+/// It's returned by hit-testing methods, and can be compared with the
+/// [`id`] of a `Plane`.
+///
+/// It's only valid while the plane it identifies is alive.
+///
+/// # Used by
+/// - [`Plane.hit_test`][crate::Plane#method.hit_test]
+/// - [`Notcurses.plane_at`][crate::Notcurses#method.plane_at]
+///
+/// [`Plane`]: crate::Plane
+/// [`id`]: crate::Plane#method.id
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlaneId(pub(crate) *mut NcPlane);
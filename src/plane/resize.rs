@@ -0,0 +1,89 @@
+// notcurses::plane::resize
+//
+//!
+//
+
+use crate::{
+    error::NotcursesResult as Result,
+    plane::Plane,
+    sys::{c_api, NcPlane},
+    RESIZE_CALLBACKS,
+};
+use core::{ffi::c_int, mem::ManuallyDrop};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// A closure called when the parent of a [`Plane`] is resized.
+///
+/// The following `Plane` methods can be used directly as ready-made callbacks:
+/// - [`resize_realign`][Plane#method.resize_realign]
+/// - [`resize_maximize`][Plane#method.resize_maximize]
+/// - [`resize_maximize_visual`][Plane#method.resize_maximize_visual]
+///
+/// # Used by
+/// - [`Plane.set_resize_cb`][Plane#method.set_resize_cb]
+/// - [`PlaneBuilder.resize_cb`][crate::PlaneBuilder#method.resize_cb]
+pub type ResizeCallback = Box<dyn FnMut(&mut Plane) -> Result<()>>;
+
+// Registers the `callback` for the plane, and sets the C trampoline.
+pub(crate) fn set(ncplane: *mut NcPlane, callback: ResizeCallback) {
+    RESIZE_CALLBACKS.with(|refcell| {
+        refcell.borrow_mut().insert(ncplane as usize, callback);
+    });
+    unsafe { c_api::ncplane_set_resizecb(ncplane, Some(trampoline)) };
+}
+
+// Unregisters the callback of the plane, if any, and unsets the C trampoline.
+pub(crate) fn unset(ncplane: *mut NcPlane) {
+    if is_set(ncplane) {
+        unsafe { c_api::ncplane_set_resizecb(ncplane, None) };
+    }
+    forget(ncplane);
+}
+
+// Unregisters the callback of the plane, if any, without touching the plane.
+//
+// Used when the plane is about to be destroyed.
+pub(crate) fn forget(ncplane: *mut NcPlane) {
+    // the callback is dropped outside of the borrow, in case it owns a `Plane`.
+    let _callback =
+        RESIZE_CALLBACKS.with(|refcell| refcell.borrow_mut().remove(&(ncplane as usize)));
+}
+
+// Unregisters all the callbacks, once all the planes have been destroyed.
+pub(crate) fn forget_all() {
+    let _callbacks = RESIZE_CALLBACKS.with(|refcell| refcell.take());
+}
+
+// Returns `true` if the plane has our trampoline set as its resize callback.
+pub(crate) fn is_set(ncplane: *mut NcPlane) -> bool {
+    let current = unsafe { c_api::ncplane_resizecb(ncplane) };
+    matches!(current, Some(cb) if cb as *const () == trampoline as *const ())
+}
+
+// The C callback that forwards to the Rust closure registered for the plane.
+unsafe extern "C" fn trampoline(ncplane: *mut NcPlane) -> c_int {
+    let key = ncplane as usize;
+
+    // take the callback out, so that it can freely (un)set callbacks itself.
+    let callback = RESIZE_CALLBACKS.with(|refcell| refcell.borrow_mut().remove(&key));
+    let mut callback = match callback {
+        Some(cb) => cb,
+        None => return c_api::NCRESULT_OK,
+    };
+
+    // the plane is owned elsewhere, so it must not be destroyed here.
+    let mut plane = ManuallyDrop::new(Plane { nc: ncplane });
+    let res = catch_unwind(AssertUnwindSafe(|| callback(&mut plane)));
+
+    // put it back, unless it was unset or replaced from within the callback.
+    if is_set(ncplane) {
+        RESIZE_CALLBACKS.with(|refcell| {
+            refcell.borrow_mut().entry(key).or_insert(callback);
+        });
+    }
+
+    match res {
+        Ok(Ok(())) => c_api::NCRESULT_OK,
+        _ => c_api::NCRESULT_ERR,
+    }
+}
@@ -0,0 +1,486 @@
+// notcurses::plane::snapshot
+//
+//!
+//
+
+use crate::{
+    color::{Alpha, Channel, Channels},
+    error::NotcursesResult as Result,
+    plane::{Plane, Style},
+    sys::{c_api, NcCell},
+    Position, Size,
+};
+use core::fmt::Write as _;
+use std::{fs, io::ErrorKind, path::Path};
+
+/// The environment variable that makes [`PlaneSnapshot.assert_matches`]
+/// overwrite the golden files instead of comparing against them.
+///
+/// [`PlaneSnapshot.assert_matches`]: PlaneSnapshot#method.assert_matches
+pub const UPDATE_SNAPSHOTS_VAR: &str = "NOTCURSES_UPDATE_SNAPSHOTS";
+
+/// A copy of every cell of a [`Plane`], for snapshot testing.
+///
+/// It's serialized with [`Display`] into a stable, human-readable format:
+///
+/// ```txt
+/// size 6x2
+/// text
+/// |hello |
+/// |world!|
+/// attributes
+/// |AAAAAB|
+/// |BBBBBB|
+/// legend
+/// A fg=#FFFFFF bg=default style=bold
+/// B fg=#FF0000 bg=#000000/blend style=none
+/// ```
+///
+/// - The `text` rows contain the grapheme clusters of the cells, with wide
+///   glyphs spanning several columns, and empty cells shown as spaces.
+/// - The `attributes` rows contain a key for the colors and styles of each
+///   cell, which are spelled out in the `legend`, in order of appearance.
+///
+/// [`Display`]: core::fmt::Display
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let mut nc = Notcurses::headless((80, 24))?;
+/// let mut plane = Plane::new_sized(&mut nc, (20, 3))?;
+/// putstr!(plane, "hello world")?;
+/// assert_plane_snapshot!(plane, "hello_world");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlaneSnapshot {
+    size: Size,
+    cells: Vec<SnapshotCell>,
+}
+
+/// A single cell of a [`PlaneSnapshot`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotCell {
+    /// The grapheme cluster, empty if the cell is empty.
+    pub egc: String,
+    /// The number of columns the grapheme cluster occupies.
+    pub width: u8,
+    /// The foreground and background channels.
+    pub channels: Channels,
+    /// The styles.
+    pub style: Style,
+}
+
+mod core_impls {
+    use super::{
+        attribute_key, channel_to_string, style_to_string, Channels, PlaneSnapshot, Style,
+    };
+    use core::fmt;
+
+    impl fmt::Display for PlaneSnapshot {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let (width, height): (u32, u32) = self.size.into();
+            writeln!(f, "size {width}x{height}")?;
+
+            writeln!(f, "text")?;
+            for row in self.rows() {
+                let mut line = String::from("|");
+                let mut skip = 0;
+                for cell in row {
+                    // the rest of the columns of a wide glyph
+                    if skip > 0 {
+                        skip -= 1;
+                        continue;
+                    }
+                    if cell.egc.is_empty() {
+                        line.push(' ');
+                    } else {
+                        line.push_str(&cell.egc);
+                        skip = cell.width.saturating_sub(1);
+                    }
+                }
+                writeln!(f, "{line}|")?;
+            }
+
+            writeln!(f, "attributes")?;
+            let mut legend: Vec<(Channels, Style)> = vec![];
+            for row in self.rows() {
+                let mut line = String::from("|");
+                for cell in row {
+                    let attributes = (cell.channels, cell.style);
+                    let index = match legend.iter().position(|a| *a == attributes) {
+                        Some(index) => index,
+                        None => {
+                            legend.push(attributes);
+                            legend.len() - 1
+                        }
+                    };
+                    line.push(attribute_key(index));
+                }
+                writeln!(f, "{line}|")?;
+            }
+
+            writeln!(f, "legend")?;
+            for (index, (channels, style)) in legend.iter().enumerate() {
+                writeln!(
+                    f,
+                    "{} fg={} bg={} style={}",
+                    attribute_key(index),
+                    channel_to_string(channels.fg()),
+                    channel_to_string(channels.bg()),
+                    style_to_string(*style),
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// # constructors
+impl PlaneSnapshot {
+    /// Returns a new snapshot of all the cells of the `plane`.
+    pub fn new(plane: &mut Plane) -> Result<PlaneSnapshot> {
+        let size = plane.size();
+        let (width, height): (u32, u32) = size.into();
+
+        let mut cells = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let cell = plane.cell_at((x, y))?;
+                let egc = cell.egc(plane).to_string();
+                let (channels, style) = (cell.channels(), cell.styles());
+
+                // the cell may hold a copy of the egc in the plane's pool.
+                let mut nccell = NcCell::from(cell);
+                let width = nccell.width;
+                unsafe { c_api::nccell_release(plane.nc, &mut nccell) };
+
+                cells.push(SnapshotCell {
+                    egc,
+                    width,
+                    channels,
+                    style,
+                });
+            }
+        }
+        Ok(Self { size, cells })
+    }
+}
+
+/// # methods
+impl PlaneSnapshot {
+    /// Returns the size of the snapshot.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the cell at `position`, if it's inside the snapshot.
+    pub fn cell(&self, position: impl Into<Position>) -> Option<&SnapshotCell> {
+        let (x, y): (i32, i32) = position.into().into();
+        let (width, height): (i32, i32) = self.size.into();
+        if (0..width).contains(&x) && (0..height).contains(&y) {
+            self.cells.get((y * width + x) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the rows of cells.
+    pub fn rows(&self) -> impl Iterator<Item = &[SnapshotCell]> {
+        let (width, _): (u32, u32) = self.size.into();
+        self.cells.chunks(width.max(1) as usize)
+    }
+
+    /// Compares the snapshot against the golden file at `path`.
+    ///
+    /// If the [`UPDATE_SNAPSHOTS_VAR`] environment variable is set,
+    /// the file is written instead.
+    ///
+    /// See also the [`assert_plane_snapshot!`][crate::assert_plane_snapshot]
+    /// macro.
+    ///
+    /// # Panics
+    /// - if the snapshot doesn't match the golden file, printing a colorized
+    ///   diff, unless the `NO_COLOR` environment variable is set.
+    /// - if the golden file doesn't exist, so that a missing file
+    ///   doesn't make the test pass silently.
+    /// - if the golden file can't be read or written.
+    #[track_caller]
+    pub fn assert_matches(&self, path: impl AsRef<Path>) {
+        let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some();
+        self.check(path.as_ref(), update);
+    }
+
+    // Compares the snapshot against the golden file at `path`,
+    // or writes it, if `update` is true.
+    #[track_caller]
+    fn check(&self, path: &Path, update: bool) {
+        let actual = self.to_string();
+
+        match fs::read_to_string(path) {
+            Ok(expected) if !update => {
+                let expected = expected.replace("\r\n", "\n");
+                if expected != actual {
+                    let color = std::env::var_os("NO_COLOR").is_none();
+                    panic!(
+                        "plane snapshot mismatch: {}\n{}(set {UPDATE_SNAPSHOTS_VAR}=1 to update)",
+                        path.display(),
+                        diff(&expected, &actual, color),
+                    );
+                }
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound && !update => {
+                panic!(
+                    "missing plane snapshot: {}\n(set {UPDATE_SNAPSHOTS_VAR}=1 to create it)",
+                    path.display()
+                );
+            }
+            Err(error) if !update => {
+                panic!(
+                    "couldn't read the plane snapshot {}: {error}",
+                    path.display()
+                );
+            }
+            _ => {
+                if let Some(dir) = path.parent() {
+                    if let Err(error) = fs::create_dir_all(dir) {
+                        panic!("couldn't create {}: {error}", dir.display());
+                    }
+                }
+                if let Err(error) = fs::write(path, actual) {
+                    panic!(
+                        "couldn't write the plane snapshot {}: {error}",
+                        path.display()
+                    );
+                }
+                eprintln!("wrote the plane snapshot {}", path.display());
+            }
+        }
+    }
+}
+
+// Returns the legend key of the attributes at `index`.
+fn attribute_key(index: usize) -> char {
+    const KEYS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    match KEYS.get(index) {
+        Some(key) => *key as char,
+        // continues with the single-width latin extended letters.
+        None => char::from_u32(0x100 + (index - KEYS.len()) as u32).unwrap_or('?'),
+    }
+}
+
+// Returns the stable representation of a channel.
+fn channel_to_string(channel: Channel) -> String {
+    let mut string = if channel.is_default() {
+        "default".to_string()
+    } else if channel.is_palindex() {
+        format!["palette({})", channel.palindex()]
+    } else {
+        format!["#{:02X}{:02X}{:02X}", channel.r(), channel.g(), channel.b()]
+    };
+    match channel.alpha() {
+        Alpha::Opaque => (),
+        Alpha::Transparent => string += "/transparent",
+        Alpha::Blend => string += "/blend",
+        Alpha::HighContrast => string += "/highcontrast",
+    }
+    string
+}
+
+// Returns the stable representation of a style.
+fn style_to_string(style: Style) -> String {
+    let names = [
+        (Style::Bold, "bold"),
+        (Style::Italic, "italic"),
+        (Style::Struck, "struck"),
+        (Style::Underline, "underline"),
+        (Style::Undercurl, "undercurl"),
+    ];
+    let styles: Vec<&str> = names
+        .iter()
+        .filter(|(s, _)| style.has(*s))
+        .map(|(_, name)| *name)
+        .collect();
+    if styles.is_empty() {
+        "none".to_string()
+    } else {
+        styles.join("+")
+    }
+}
+
+// Returns a line diff between `expected` and `actual`,
+// optionally colorized with ANSI escape sequences.
+fn diff(expected: &str, actual: &str, color: bool) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+
+    // longest common subsequence lengths, of the suffixes.
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (red, green, reset) = if color {
+        ("\x1b[31m", "\x1b[32m", "\x1b[0m")
+    } else {
+        ("", "", "")
+    };
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            let _ = writeln!(out, "  {}", old[i]);
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            let _ = writeln!(out, "{green}+ {}{reset}", new[j]);
+            j += 1;
+        } else {
+            let _ = writeln!(out, "{red}- {}{reset}", old[i]);
+            i += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Alpha, Channel, Channels, PlaneSnapshot, SnapshotCell, Style};
+    use crate::Size;
+    use std::{fs, path::PathBuf};
+
+    fn cell(egc: &str, width: u8, channels: Channels, style: Style) -> SnapshotCell {
+        SnapshotCell {
+            egc: egc.into(),
+            width,
+            channels,
+            style,
+        }
+    }
+
+    // A path in the temporary directory, unique to this process,
+    // that is removed on drop.
+    struct TempPath(PathBuf);
+
+    impl TempPath {
+        fn new(name: &str) -> Self {
+            let file = format!["notcurses-{}-{name}.snap", std::process::id()];
+            Self(std::env::temp_dir().join(file))
+        }
+    }
+    impl Drop for TempPath {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    // Returns the snapshot of the documentation of `PlaneSnapshot`.
+    fn hello_world() -> PlaneSnapshot {
+        let a = Channels::combine(Channel::from_rgb(0xFFFFFF), Channel::with_default());
+        let b = Channels::combine(
+            Channel::from_rgb(0xFF0000),
+            Channel::from_rgb_alpha(0x000000, Alpha::Blend),
+        );
+        let mut cells: Vec<SnapshotCell> = "hello"
+            .chars()
+            .map(|c| cell(&c.to_string(), 1, a, Style::Bold))
+            .collect();
+        cells.push(cell("", 0, b, Style::None));
+        cells.extend(
+            "world!"
+                .chars()
+                .map(|c| cell(&c.to_string(), 1, b, Style::None)),
+        );
+        PlaneSnapshot {
+            size: Size::new(6, 2),
+            cells,
+        }
+    }
+
+    #[test]
+    fn text_format() {
+        assert_eq![
+            hello_world().to_string(),
+            "size 6x2\n\
+            text\n\
+            |hello |\n\
+            |world!|\n\
+            attributes\n\
+            |AAAAAB|\n\
+            |BBBBBB|\n\
+            legend\n\
+            A fg=#FFFFFF bg=default style=bold\n\
+            B fg=#FF0000 bg=#000000/blend style=none\n"
+        ];
+    }
+
+    #[test]
+    fn text_format_wide() {
+        let c = Channels::with_default();
+        let snapshot = PlaneSnapshot {
+            size: Size::new(4, 1),
+            cells: vec![
+                cell("字", 2, c, Style::None),
+                cell("", 0, c, Style::None),
+                cell("a", 1, c, Style::Italic | Style::Underline),
+                cell("", 0, c, Style::None),
+            ],
+        };
+        assert_eq![
+            snapshot.to_string(),
+            "size 4x1\n\
+            text\n\
+            |字a |\n\
+            attributes\n\
+            |AABA|\n\
+            legend\n\
+            A fg=default bg=default style=none\n\
+            B fg=default bg=default style=italic+underline\n"
+        ];
+    }
+
+    #[test]
+    fn diff_lines() {
+        assert_eq![
+            diff("a\nb\nc\n", "a\nx\nc\nd\n", false),
+            "  a\n+ x\n- b\n  c\n+ d\n"
+        ];
+        assert_eq![diff("a\n", "a\n", false), "  a\n"];
+        assert_eq![
+            diff("a\n", "b\n", true),
+            "\x1b[32m+ b\x1b[0m\n\x1b[31m- a\x1b[0m\n"
+        ];
+    }
+
+    #[test]
+    #[should_panic(expected = "missing plane snapshot")]
+    fn check_missing() {
+        hello_world().check(&TempPath::new("missing").0, false);
+    }
+
+    #[test]
+    fn check_update() {
+        let path = TempPath::new("update");
+        hello_world().check(&path.0, true);
+        hello_world().check(&path.0, false);
+        assert_eq![
+            fs::read_to_string(&path.0).unwrap(),
+            hello_world().to_string()
+        ];
+    }
+
+    #[test]
+    #[should_panic(expected = "plane snapshot mismatch")]
+    fn check_mismatch() {
+        let path = TempPath::new("mismatch");
+        fs::write(&path.0, "size 0x0\n").unwrap();
+        hello_world().check(&path.0, false);
+    }
+}
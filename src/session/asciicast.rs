@@ -0,0 +1,313 @@
+// notcurses::session::asciicast
+//
+//!
+//
+
+use crate::{
+    input::{Input, InputType, Key, KeyMod, Received},
+    Position, Size,
+};
+use core::fmt::Write as _;
+
+/// Returns the header line of an asciicast v2 file.
+pub(super) fn header(size: Size, timestamp: u64) -> String {
+    let (width, height): (u32, u32) = size.into();
+    format![
+        "{{\"version\": 2, \"width\": {width}, \"height\": {height}, \"timestamp\": {timestamp}}}"
+    ]
+}
+
+/// Returns the terminal size declared in an asciicast v2 header line.
+pub(super) fn parse_header(line: &str) -> Option<Size> {
+    let version = parse_field(line, "version")?;
+    if version != 2 {
+        return None;
+    }
+    let width = parse_field(line, "width")?;
+    let height = parse_field(line, "height")?;
+    Some(Size::new(width as i32, height as i32))
+}
+
+// Returns the unsigned integer value of a top-level `field` of a JSON object.
+fn parse_field(line: &str, field: &str) -> Option<u32> {
+    let start = line.find(&format!["\"{field}\""])? + field.len() + 2;
+    let rest = line[start..].trim_start().strip_prefix(':')?.trim_start();
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// Returns an event line of an asciicast v2 file.
+pub(super) fn event(time: f64, code: &str, data: &str) -> String {
+    format!["[{time:.6}, {}, {}]", json_string(code), json_string(data)]
+}
+
+/// Returns the time, code and data of an asciicast v2 event line.
+pub(super) fn parse_event(line: &str) -> Option<(f64, String, String)> {
+    let rest = line.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let (time, rest) = rest.split_once(',')?;
+    let time = time.trim().parse().ok()?;
+    let (code, rest) = parse_json_string(rest.trim_start())?;
+    let rest = rest.trim_start().strip_prefix(',')?;
+    let (data, rest) = parse_json_string(rest.trim_start())?;
+    if !rest.trim().is_empty() {
+        return None;
+    }
+    Some((time, code, data))
+}
+
+/// Returns the string quoted and escaped as a JSON string.
+pub(super) fn json_string(string: &str) -> String {
+    let mut json = String::with_capacity(string.len() + 2);
+    json.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write![json, "\\u{:04x}", c as u32];
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+// Parses a JSON string at the start of `json`, returning it unescaped,
+// together with the rest of the input.
+fn parse_json_string(json: &str) -> Option<(String, &str)> {
+    let mut chars = json.strip_prefix('"')?.char_indices();
+    let mut string = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((string, &json[i + 2..])),
+            '\\' => match chars.next()?.1 {
+                '"' => string.push('"'),
+                '\\' => string.push('\\'),
+                '/' => string.push('/'),
+                'b' => string.push('\u{8}'),
+                'f' => string.push('\u{c}'),
+                'n' => string.push('\n'),
+                'r' => string.push('\r'),
+                't' => string.push('\t'),
+                'u' => {
+                    let mut code = parse_hex4(&mut chars)?;
+                    // a high surrogate must be followed by an escaped low surrogate.
+                    if (0xD800..0xDC00).contains(&code) {
+                        if chars.next()?.1 != '\\' || chars.next()?.1 != 'u' {
+                            return None;
+                        }
+                        let low = parse_hex4(&mut chars)?;
+                        code = 0x10000 + ((code - 0xD800) << 10) + (low.checked_sub(0xDC00)?);
+                    }
+                    string.push(char::from_u32(code)?);
+                }
+                _ => return None,
+            },
+            c => string.push(c),
+        }
+    }
+    None
+}
+
+// Parses the 4 hexadecimal digits of a JSON `\u` escape.
+fn parse_hex4(chars: &mut core::str::CharIndices) -> Option<u32> {
+    let mut code = 0;
+    for _ in 0..4 {
+        code = code * 16 + chars.next()?.1.to_digit(16)?;
+    }
+    Some(code)
+}
+
+/// Returns the data of an input event, e.g.: `key=0x110004 mod=0x4 type=press cell=3,4`.
+pub(super) fn encode_input(input: &Input) -> String {
+    let mut data = match input.received {
+        Received::Char(c) => format!["char={:#x}", c as u32],
+        Received::Key(k) => format!["key={:#x}", crate::sys::NcKey::from(k).0],
+        Received::NoInput => "none".to_string(),
+    };
+    if !input.keymod.has_none() {
+        let _ = write![data, " mod={:#x}", u32::from(input.keymod)];
+    }
+    let itype = match input.itype {
+        InputType::Unknown => None,
+        InputType::Press => Some("press"),
+        InputType::Repeat => Some("repeat"),
+        InputType::Release => Some("release"),
+    };
+    if let Some(itype) = itype {
+        let _ = write![data, " type={itype}"];
+    }
+    if let Some(cell) = input.cell {
+        let (x, y): (i32, i32) = cell.into();
+        let _ = write![data, " cell={x},{y}"];
+    }
+    if let Some(offset) = input.offset {
+        let (x, y): (i32, i32) = offset.into();
+        let _ = write![data, " offset={x},{y}"];
+    }
+    data
+}
+
+/// Returns the inputs of an input event.
+///
+/// Data that isn't in the [`encode_input`] format, like the raw keystrokes
+/// recorded by other programs, is returned as one input per `char`.
+pub(super) fn decode_input(data: &str) -> Vec<Input> {
+    match decode_encoded_input(data) {
+        Some(input) => vec![input],
+        None => data.chars().map(Input::from).collect(),
+    }
+}
+
+// Returns the input of an input event in the `encode_input` format.
+fn decode_encoded_input(data: &str) -> Option<Input> {
+    let mut tokens = data.split(' ');
+    let received = match tokens.next()?.split_once('=') {
+        Some(("char", code)) => Received::Char(char::from_u32(parse_hex(code)?)?),
+        Some(("key", code)) => Received::Key(Key::new(parse_hex(code)?)?),
+        None if data.starts_with("none") => Received::NoInput,
+        _ => return None,
+    };
+    let mut input = Input::from(received);
+    for token in tokens {
+        match token.split_once('=')? {
+            ("mod", code) => input.keymod = KeyMod::from(parse_hex(code)?),
+            ("type", "press") => input.itype = InputType::Press,
+            ("type", "repeat") => input.itype = InputType::Repeat,
+            ("type", "release") => input.itype = InputType::Release,
+            ("cell", xy) => input.cell = Some(parse_position(xy)?),
+            ("offset", xy) => input.offset = Some(parse_position(xy)?),
+            _ => return None,
+        }
+    }
+    Some(input)
+}
+
+// Parses a `0x`-prefixed hexadecimal number.
+fn parse_hex(code: &str) -> Option<u32> {
+    u32::from_str_radix(code.strip_prefix("0x")?, 16).ok()
+}
+
+// Parses a position formatted as `x,y`.
+fn parse_position(xy: &str) -> Option<Position> {
+    let (x, y) = xy.split_once(',')?;
+    Some(Position::new(x.parse().ok()?, y.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs() -> Vec<Input> {
+        let mut mouse = Input::mouse(Key::Button1, InputType::Release, (3, 4));
+        mouse.offset = Some(Position::new(5, 7));
+        vec![
+            Input::from('q'),
+            Input::from('é'),
+            Input::from('😀'),
+            Input::from(Key::Up),
+            Input::new(Key::F05, KeyMod::Ctrl | KeyMod::Alt),
+            Input::new('x', KeyMod::Super),
+            mouse,
+            Input::from(Received::NoInput),
+        ]
+    }
+
+    #[test]
+    fn input_roundtrip() {
+        for input in inputs() {
+            let data = encode_input(&input);
+            assert_eq![decode_input(&data), vec![input], "{data}"];
+        }
+    }
+
+    #[test]
+    fn input_format() {
+        assert_eq!["char=0x71", encode_input(&Input::from('q'))];
+        let mut mouse = Input::mouse(Key::Button1, InputType::Press, (3, 4));
+        mouse.offset = Some(Position::new(5, 7));
+        assert_eq![
+            format![
+                "key={:#x} type=press cell=3,4 offset=5,7",
+                crate::sys::NcKey::from(Key::Button1).0
+            ],
+            encode_input(&mouse)
+        ];
+    }
+
+    #[test]
+    fn input_raw_keystrokes() {
+        assert_eq![
+            vec![Input::from('l'), Input::from('s'), Input::from('\r')],
+            decode_input("ls\r")
+        ];
+        // an unknown field makes it raw keystrokes.
+        assert_eq![15, decode_input("char=0x71 foo=1").len()];
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let strings = [
+            "",
+            "plain",
+            "quo\"te \\ /",
+            "\n\r\t\x1b[1m\u{7f}",
+            "né 字 😀",
+        ];
+        for string in strings {
+            let json = json_string(string);
+            assert_eq![
+                Some((string.to_string(), "")),
+                parse_json_string(&json),
+                "{json}"
+            ];
+        }
+        assert_eq!["\"\\u001b[H\"", json_string("\x1b[H")];
+    }
+
+    #[test]
+    fn json_escapes() {
+        assert_eq![
+            Some(("é😀/\u{8}\u{c}".to_string(), ", rest")),
+            parse_json_string("\"\\u00e9\\ud83d\\ude00\\/\\b\\f\", rest")
+        ];
+        // unpaired surrogates, unknown escapes and unterminated strings.
+        assert_eq![None, parse_json_string("\"\\ud83d\"")];
+        assert_eq![None, parse_json_string("\"\\ud83d\\u0041\"")];
+        assert_eq![None, parse_json_string("\"\\x41\"")];
+        assert_eq![None, parse_json_string("\"abc")];
+        assert_eq![None, parse_json_string("abc\"")];
+    }
+
+    #[test]
+    fn header_roundtrip() {
+        let line = header(Size::new(80, 24), 1_700_000_000);
+        assert_eq![Some(Size::new(80, 24)), parse_header(&line)];
+        assert_eq![
+            Some(Size::new(100, 30)),
+            parse_header(r#"{"version": 2, "width": 100, "height": 30, "env": {"TERM": "xterm"}}"#)
+        ];
+        assert_eq![
+            None,
+            parse_header(r#"{"version": 1, "width": 80, "height": 24}"#)
+        ];
+        assert_eq![None, parse_header(r#"{"version": 2, "width": 80}"#)];
+    }
+
+    #[test]
+    fn event_roundtrip() {
+        let line = event(1.5, "i", "key=0x1 \"quoted\"\n");
+        assert_eq![
+            Some((1.5, "i".to_string(), "key=0x1 \"quoted\"\n".to_string())),
+            parse_event(&line)
+        ];
+        assert_eq![None, parse_event("[1.5, \"o\"]")];
+        assert_eq![None, parse_event("[1.5, \"o\", \"data\", 3]")];
+    }
+}
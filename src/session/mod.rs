@@ -0,0 +1,14 @@
+// notcurses::session
+//
+//! Recording and replaying of sessions.
+//
+
+mod asciicast;
+mod recorder;
+mod replayer;
+
+pub(crate) use recorder::{
+    is_recording, record_frame, record_input, start as start_recording, stop as stop_recording,
+    Recorder,
+};
+pub use replayer::Replayer;
@@ -0,0 +1,314 @@
+// notcurses::session::recorder
+//
+//!
+//
+
+use super::asciicast;
+use crate::{
+    color::{Channel, Channels},
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::{Input, Received},
+    plane::Style,
+    sys::{c_api, Nc, NcChannels, NcStyle},
+    Size, RECORDER, RECORDER_ERROR,
+};
+use core::fmt::Write as _;
+use std::{
+    ffi::CString,
+    io::Write,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Records the inputs and the rendered frames into an asciicast v2 stream.
+pub(crate) struct Recorder {
+    writer: Box<dyn Write>,
+    start: Instant,
+    // the rows of the last recorded frame, as written out.
+    rows: Vec<String>,
+}
+
+impl Recorder {
+    // Writes the header and returns a new recorder.
+    fn new(mut writer: Box<dyn Write>, size: Size) -> Result<Self> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        writeln!(writer, "{}", asciicast::header(size, timestamp))?;
+        Ok(Self {
+            writer,
+            start: Instant::now(),
+            rows: vec![],
+        })
+    }
+
+    // Writes an event line, timestamped since the start of the recording.
+    fn write_event(&mut self, code: &str, data: &str) -> Result<()> {
+        let time = self.start.elapsed().as_secs_f64();
+        writeln!(self.writer, "{}", asciicast::event(time, code, data))?;
+        Ok(())
+    }
+
+    // Writes the rows of the `frame` that changed since the last one.
+    fn write_frame(&mut self, frame: Vec<String>) -> Result<()> {
+        let mut data = String::new();
+        let redraw = frame.len() != self.rows.len();
+        if redraw {
+            data.push_str("\x1b[H\x1b[2J");
+        }
+        for (y, row) in frame.iter().enumerate() {
+            if redraw || self.rows[y] != *row {
+                let _ = write![data, "\x1b[{};1H{row}", y + 1];
+            }
+        }
+        self.rows = frame;
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.write_event("o", &data)
+    }
+}
+
+/// Starts recording into `writer`, replacing any previous recording.
+///
+/// Returns the error of the previous recording instead, if it failed.
+pub(crate) fn start(writer: Box<dyn Write>, size: Size) -> Result<()> {
+    stop()?;
+    let recorder = Recorder::new(writer, size)?;
+    RECORDER.with(|refcell| refcell.replace(Some(recorder)));
+    Ok(())
+}
+
+/// Stops recording, flushing the writer, if there's a recording.
+///
+/// Returns the error that stopped the recording early, if any.
+pub(crate) fn stop() -> Result<()> {
+    if let Some(error) = RECORDER_ERROR.with(|refcell| refcell.take()) {
+        return Err(error);
+    }
+    if let Some(mut recorder) = RECORDER.with(|refcell| refcell.take()) {
+        recorder.writer.flush()?;
+    }
+    Ok(())
+}
+
+// Stops recording because of an `error`, keeping it to be returned by `stop`.
+fn fail(error: Error) {
+    RECORDER.with(|refcell| refcell.take());
+    RECORDER_ERROR.with(|refcell| refcell.replace(Some(error)));
+}
+
+/// Returns `true` if there's a recording.
+pub(crate) fn is_recording() -> bool {
+    RECORDER.with(|refcell| refcell.borrow().is_some())
+}
+
+/// Records the `input`, if there's a recording.
+///
+/// Recording is best-effort: if writing fails the recording is stopped,
+/// and the error is returned by [`stop`] instead.
+pub(crate) fn record_input(input: &Input) {
+    if input.received == Received::NoInput {
+        return;
+    }
+    let res = RECORDER.with(|refcell| match refcell.borrow_mut().as_mut() {
+        Some(recorder) => recorder.write_event("i", &asciicast::encode_input(input)),
+        None => Ok(()),
+    });
+    if let Err(error) = res {
+        fail(error);
+    }
+}
+
+/// Records the last rasterized frame of the `nc` screen, if there's a recording.
+///
+/// Recording is best-effort, like in [`record_input`].
+pub(crate) fn record_frame(nc: &mut Nc) {
+    if !is_recording() {
+        return;
+    }
+    let frame = screen_rows(nc);
+    let res = RECORDER.with(|refcell| match refcell.borrow_mut().as_mut() {
+        Some(recorder) => recorder.write_frame(frame),
+        None => Ok(()),
+    });
+    if let Err(error) = res {
+        fail(error);
+    }
+}
+
+// Returns every row of the screen, with its colors and styles as SGR sequences.
+fn screen_rows(nc: &mut Nc) -> Vec<String> {
+    let (height, width) = nc.term_dim_yx();
+    let mut rows = Vec::with_capacity(height as usize);
+    for y in 0..height {
+        let mut row = String::new();
+        let mut last_attributes = None;
+        let mut skip = 0;
+        for x in 0..width {
+            // the columns covered by the last wide glyph.
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+            let egc = nc
+                .at_yx(y, x, &mut style, &mut channels)
+                .unwrap_or_default();
+
+            let attributes = (Style::from(style), Channels::from(channels));
+            if last_attributes != Some(attributes) {
+                row.push_str(&sgr(attributes.0, attributes.1));
+                last_attributes = Some(attributes);
+            }
+            if egc.is_empty() {
+                row.push(' ');
+            } else {
+                skip = egc_width(&egc).saturating_sub(1);
+                row.push_str(&egc);
+            }
+        }
+        row.push_str("\x1b[0m");
+        rows.push(row);
+    }
+    rows
+}
+
+// Returns the number of columns occupied by the `egc`.
+fn egc_width(egc: &str) -> u32 {
+    let cstring = match CString::new(egc) {
+        Ok(cstring) => cstring,
+        Err(_) => return 1,
+    };
+    let width = unsafe {
+        c_api::ncstrwidth(
+            cstring.as_ptr(),
+            core::ptr::null_mut(),
+            core::ptr::null_mut(),
+        )
+    };
+    width.max(1) as u32
+}
+
+// Returns the SGR sequence that selects the `style` and `channels`.
+fn sgr(style: Style, channels: Channels) -> String {
+    let mut sgr = String::from("\x1b[0");
+    let codes = [
+        (Style::Bold, ";1"),
+        (Style::Italic, ";3"),
+        (Style::Underline, ";4"),
+        (Style::Undercurl, ";4:3"),
+        (Style::Struck, ";9"),
+    ];
+    for (s, code) in codes {
+        if style.has(s) {
+            sgr.push_str(code);
+        }
+    }
+    push_sgr_color(&mut sgr, channels.fg(), 38);
+    push_sgr_color(&mut sgr, channels.bg(), 48);
+    sgr.push('m');
+    sgr
+}
+
+// Pushes the SGR parameters that select the `channel` color, if not default.
+fn push_sgr_color(sgr: &mut String, channel: Channel, base: u8) {
+    if channel.is_default() {
+        return;
+    }
+    if channel.is_palindex() {
+        let _ = write![sgr, ";{base};5;{}", channel.palindex()];
+    } else {
+        let _ = write![
+            sgr,
+            ";{base};2;{};{};{}",
+            channel.r(),
+            channel.g(),
+            channel.b()
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_recording, record_input, start, stop};
+    use crate::{
+        input::{Input, InputType, Key, KeyMod, Received},
+        session::Replayer,
+        Position, Size,
+    };
+    use std::{cell::RefCell, io, rc::Rc};
+
+    // A writer into a shared buffer, that can be made to fail.
+    #[derive(Clone, Default)]
+    struct SharedWriter {
+        buffer: Rc<RefCell<Vec<u8>>>,
+        failing: Rc<RefCell<bool>>,
+    }
+    impl io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if *self.failing.borrow() {
+                return Err(io::Error::new(io::ErrorKind::Other, "failing writer"));
+            }
+            self.buffer.borrow_mut().write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut mouse = Input::mouse(Key::Button1, InputType::Press, (3, 4));
+        mouse.offset = Some(Position::new(5, 7));
+        let inputs = [
+            Input::from(Key::Up),
+            Input::new(Key::F05, KeyMod::Ctrl | KeyMod::Alt),
+            mouse,
+            Input::from('ñ'),
+        ];
+
+        let writer = SharedWriter::default();
+        start(Box::new(writer.clone()), Size::new(80, 24)).unwrap();
+        for input in &inputs {
+            record_input(input);
+            record_input(&Input::from(Received::NoInput));
+        }
+        stop().unwrap();
+        assert![!is_recording()];
+
+        let buffer = writer.buffer.borrow();
+        let replayer = Replayer::from_reader(&buffer[..]).unwrap();
+        assert_eq![Size::new(80, 24), replayer.size()];
+        let replayed: Vec<Input> = replayer.inputs().iter().map(|(_, i)| *i).collect();
+        assert_eq![&inputs[..], &replayed[..]];
+    }
+
+    #[test]
+    fn record_best_effort() {
+        let writer = SharedWriter::default();
+        start(Box::new(writer.clone()), Size::new(80, 24)).unwrap();
+        *writer.failing.borrow_mut() = true;
+
+        record_input(&Input::from('a'));
+        assert![!is_recording()];
+        assert![stop().is_err()];
+        assert![stop().is_ok()];
+
+        // a new recording can be started afterwards.
+        let writer = SharedWriter::default();
+        start(Box::new(writer.clone()), Size::new(80, 24)).unwrap();
+        record_input(&Input::from('b'));
+        stop().unwrap();
+        let buffer = writer.buffer.borrow();
+        let replayer = Replayer::from_reader(&buffer[..]).unwrap();
+        assert_eq![
+            vec![Input::from('b')],
+            replayer
+                .inputs()
+                .iter()
+                .map(|(_, i)| *i)
+                .collect::<Vec<_>>()
+        ];
+    }
+}
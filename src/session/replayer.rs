@@ -0,0 +1,119 @@
+// notcurses::session::replayer
+//
+//!
+//
+
+use super::asciicast;
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::Input,
+    Size,
+};
+use core::time::Duration;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+/// The inputs of a session recorded by [`Notcurses.start_recording`].
+///
+/// They can be fed back to an app with [`Notcurses.replay`]
+/// or [`Notcurses.replay_timed`], in order to reproduce the session.
+///
+/// Other asciicast v2 files are also supported, in which case each `char`
+/// of their input events is replayed as a separate [`Input`].
+///
+/// [`Notcurses.start_recording`]: crate::Notcurses#method.start_recording
+/// [`Notcurses.replay`]: crate::Notcurses#method.replay
+/// [`Notcurses.replay_timed`]: crate::Notcurses#method.replay_timed
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let cast = r#"{"version": 2, "width": 80, "height": 24}
+/// [0.5, "i", "hi"]
+/// [1.0, "i", "key=0x11037a mod=0x4"]"#;
+///
+/// let replayer = Replayer::from_reader(cast.as_bytes())?;
+/// assert_eq![3, replayer.inputs().len()];
+///
+/// let mut nc = Notcurses::headless(replayer.size())?;
+/// nc.replay(&replayer);
+/// assert![nc.get_event()?.is_char('h')];
+/// assert![nc.get_event()?.is_char('i')];
+/// assert_eq![Input::new(Key::Up, KeyMod::Ctrl), nc.get_event()?];
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Replayer {
+    size: Size,
+    inputs: Vec<(Duration, Input)>,
+}
+
+/// # constructors
+impl Replayer {
+    /// Returns a new `Replayer` from an asciicast v2 `reader`.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
+        let mut lines = reader.lines();
+        let size = match lines.next().transpose()? {
+            Some(header) => asciicast::parse_header(&header),
+            None => None,
+        };
+        let size = match size {
+            Some(size) => size,
+            None => return Error::msg("Invalid asciicast v2 header."),
+        };
+
+        let mut inputs = vec![];
+        for (number, line) in lines.enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (time, code, data) = match asciicast::parse_event(&line) {
+                Some(event) => event,
+                None => {
+                    return Error::msg(&format![
+                        "Invalid asciicast v2 event at line {}.",
+                        number + 2
+                    ])
+                }
+            };
+            if code == "i" {
+                let time = Duration::from_secs_f64(time.max(0.0));
+                let decoded = asciicast::decode_input(&data);
+                inputs.extend(decoded.into_iter().map(|input| (time, input)));
+            }
+        }
+        Ok(Self { size, inputs })
+    }
+
+    /// Returns a new `Replayer` from the asciicast v2 file at `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+}
+
+/// # methods
+impl Replayer {
+    /// Returns the size of the terminal the session was recorded in.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the recorded inputs, with their time since the start of the session.
+    pub fn inputs(&self) -> &[(Duration, Input)] {
+        &self.inputs
+    }
+
+    /// Returns the time of the last recorded input.
+    pub fn duration(&self) -> Duration {
+        self.inputs
+            .last()
+            .map(|(time, _)| *time)
+            .unwrap_or_default()
+    }
+}
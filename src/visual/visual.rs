@@ -25,7 +25,7 @@ mod core_impls {
     impl Drop for Visual {
         #[inline]
         fn drop(&mut self) {
-            if crate::Notcurses::is_initialized() {
+            if crate::Notcurses::is_initialized() || crate::Direct::is_initialized() {
                 self.into_ref_mut().destroy()
             }
         }
@@ -0,0 +1,95 @@
+// notcurses::widgets::fd_plane::fd_plane
+//
+//!
+//
+
+use crate::{
+    plane::Plane,
+    sys::NcFdPlane,
+    widgets::fd_plane::{output::Output, FdPlaneBuilder},
+};
+
+/// A scrolling plane showing everything read from a file descriptor.
+///
+/// The reading happens in a separate thread managed by notcurses, which
+/// writes the data on the plane as it arrives, until the end of file.
+///
+/// When dropped, the reading stops and the file descriptor is closed.
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// let mut stdplane = Plane::new(&mut nc)?;
+/// let (done_tx, done_rx) = std::sync::mpsc::channel();
+/// let manifest = std::fs::File::open("Cargo.toml")?;
+/// let fdplane = FdPlane::builder()
+///     .on_done(move |result| done_tx.send(result.is_ok()).unwrap())
+///     .build(&mut stdplane, &manifest)?;
+/// assert![done_rx.recv().unwrap()];
+/// stdplane.render()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FdPlane {
+    pub(super) nc: *mut NcFdPlane,
+    pub(super) plane: Plane,
+    pub(super) output: *mut Output,
+}
+
+mod core_impls {
+    use super::FdPlane;
+    use core::fmt;
+
+    impl Drop for FdPlane {
+        fn drop(&mut self) {
+            // stops the reading thread before freeing the state it uses.
+            if crate::Notcurses::is_initialized() {
+                unsafe { crate::sys::c_api::ncfdplane_destroy(self.nc) };
+            }
+            drop(unsafe { Box::from_raw(self.output) });
+        }
+    }
+
+    impl fmt::Debug for FdPlane {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "FdPlane {{ {:?} }}", self.plane)
+        }
+    }
+}
+
+/// # constructors
+impl FdPlane {
+    /// Returns a new [`FdPlaneBuilder`].
+    pub fn builder() -> FdPlaneBuilder {
+        FdPlaneBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcFdPlane`].
+    pub fn into_ref(&self) -> &NcFdPlane {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcFdPlane`].
+    pub fn into_ref_mut(&mut self) -> &mut NcFdPlane {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl FdPlane {
+    /// Returns a shared reference to the plane where the data is written.
+    pub fn plane(&self) -> &Plane {
+        &self.plane
+    }
+
+    /// Returns an exclusive reference to the plane where the data is written.
+    ///
+    /// Note that the reading thread may be writing on it at the same time.
+    pub fn plane_mut(&mut self) -> &mut Plane {
+        &mut self.plane
+    }
+}
@@ -0,0 +1,147 @@
+// notcurses::widgets::fd_plane::fd_plane_builder
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{c_api, NcFdPlaneOptions},
+    widgets::fd_plane::{
+        output::{done_cb, new_plane, output_cb, Output},
+        FdPlane,
+    },
+    Position, Size,
+};
+use core::ffi::c_void;
+use std::{io, os::unix::io::AsRawFd};
+
+/// An [`FdPlane`] builder.
+#[derive(Default)]
+pub struct FdPlaneBuilder {
+    position: Position,
+    size: Option<Size>,
+    follow: bool,
+    on_done: Option<Box<dyn FnMut(io::Result<()>) + Send>>,
+}
+
+mod core_impls {
+    use super::FdPlaneBuilder;
+    use core::fmt;
+
+    impl fmt::Debug for FdPlaneBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("FdPlaneBuilder")
+                .field("position", &self.position)
+                .field("size", &self.size)
+                .field("follow", &self.follow)
+                .field("on_done", &self.on_done.is_some())
+                .finish()
+        }
+    }
+}
+
+/// # constructors
+impl FdPlaneBuilder {
+    /// Returns a new default `FdPlaneBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `FdPlane` drawn on a new child plane of the provided
+    /// `parent`, reading from the file descriptor of `fd`.
+    ///
+    /// The file descriptor is duplicated, so `fd` can still be used and
+    /// must still be closed by its owner.
+    ///
+    /// # Errors
+    /// - if the file descriptor can't be duplicated.
+    /// - if notcurses fails to create the widget.
+    pub fn build(self, parent: &mut Plane, fd: &impl AsRawFd) -> Result<FdPlane> {
+        let dup_fd = unsafe { c_api::libc::dup(fd.as_raw_fd()) };
+        if dup_fd < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        let mut plane = new_plane(parent, self.position, self.size)?;
+        let mut on_done = self.on_done;
+        let output = Box::into_raw(Box::new(Output {
+            plane: plane.into_ref_mut(),
+            pending: vec![],
+            done: on_done.take().map(|mut done| {
+                Box::new(move |errno| {
+                    done(match errno {
+                        0 => Ok(()),
+                        errno => Err(io::Error::from_raw_os_error(errno)),
+                    })
+                }) as _
+            }),
+        }));
+
+        let options = NcFdPlaneOptions {
+            curry: output as *mut c_void,
+            follow: self.follow,
+            flags: 0,
+        };
+        // the widget takes ownership of the duplicated file descriptor.
+        let ncfdplane = unsafe {
+            c_api::ncfdplane_create(
+                plane.into_ref_mut(),
+                &options,
+                dup_fd,
+                Some(output_cb),
+                Some(done_cb),
+            )
+        };
+
+        if ncfdplane.is_null() {
+            unsafe { c_api::libc::close(dup_fd) };
+            drop(unsafe { Box::from_raw(output) });
+            Error::msg("Couldn't create the `FdPlane`.")
+        } else {
+            Ok(FdPlane {
+                nc: ncfdplane,
+                plane,
+                output,
+            })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl FdPlaneBuilder {
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *`(0, 0)`*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the size of the plane.
+    ///
+    /// Default: *the rest of the parent plane, from the position*.
+    pub fn size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Keeps reading after reaching the end of file, like `tail -f`.
+    ///
+    /// Default: *`false`*.
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Sets a closure called once the reading is done, because of
+    /// the end of file or an error.
+    ///
+    /// It's called from the reading thread.
+    ///
+    /// Default: *`None`*.
+    pub fn on_done(mut self, on_done: impl FnMut(io::Result<()>) + Send + 'static) -> Self {
+        self.on_done = Some(Box::new(on_done));
+        self
+    }
+}
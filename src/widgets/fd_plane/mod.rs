@@ -0,0 +1,15 @@
+// notcurses::widgets::fd_plane
+//
+//!
+//
+
+mod fd_plane;
+mod fd_plane_builder;
+mod output;
+mod subprocess;
+mod subprocess_builder;
+
+pub use fd_plane::FdPlane;
+pub use fd_plane_builder::FdPlaneBuilder;
+pub use subprocess::Subprocess;
+pub use subprocess_builder::SubprocessBuilder;
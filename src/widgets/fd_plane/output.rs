@@ -0,0 +1,112 @@
+// notcurses::widgets::fd_plane::output
+//
+//!
+//
+
+use crate::{
+    error::NotcursesResult as Result,
+    plane::Plane,
+    sys::{c_api, NcFdPlane, NcPlane},
+    Position, Size,
+};
+use core::{ffi::c_int, mem::ManuallyDrop, slice};
+use std::{
+    ffi::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+// The state shared with the notcurses reading thread, passed as the curry.
+pub(super) struct Output {
+    // the plane where the output is written.
+    pub plane: *mut NcPlane,
+    // the trailing bytes of an incomplete UTF-8 sequence.
+    pub pending: Vec<u8>,
+    // called once reading is done, with the raw value reported by notcurses.
+    pub done: Option<Box<dyn FnMut(c_int) + Send>>,
+}
+
+// Returns a new scrolling child plane of the `parent`, for the output to be written on.
+pub(super) fn new_plane(
+    parent: &mut Plane,
+    position: Position,
+    size: Option<Size>,
+) -> Result<Plane> {
+    let size = size.unwrap_or_else(|| {
+        let (width, height): (i32, i32) = parent.size().into();
+        Size::new(
+            (width - position.x()).max(1),
+            (height - position.y()).max(1),
+        )
+    });
+    let mut plane = Plane::builder()
+        .position(position)
+        .size(size)
+        .build_child(parent)?;
+    plane.set_scrolling(true);
+    Ok(plane)
+}
+
+// The C callback that writes the data read to the plane.
+pub(super) unsafe extern "C" fn output_cb(
+    _n: *mut NcFdPlane,
+    buf: *const c_void,
+    len: usize,
+    curry: *mut c_void,
+) -> c_int {
+    let output = &mut *(curry as *mut Output);
+    output
+        .pending
+        .extend_from_slice(slice::from_raw_parts(buf as *const u8, len));
+
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        // the plane is owned by the widget, so it must not be destroyed here.
+        let mut plane = ManuallyDrop::new(Plane::from(&mut *output.plane));
+
+        let mut bytes = &output.pending[..];
+        let mut text = String::new();
+        loop {
+            match core::str::from_utf8(bytes) {
+                Ok(valid) => {
+                    text += valid;
+                    bytes = &[];
+                    break;
+                }
+                Err(err) => {
+                    let (valid, rest) = bytes.split_at(err.valid_up_to());
+                    text += core::str::from_utf8(valid).unwrap_or_default();
+                    match err.error_len() {
+                        // an invalid sequence
+                        Some(invalid) => {
+                            text.push(char::REPLACEMENT_CHARACTER);
+                            bytes = &rest[invalid..];
+                        }
+                        // an incomplete sequence, to be completed by the next read
+                        None => {
+                            bytes = rest;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        let _ = plane.putstr(&text);
+        output.pending = bytes.to_vec();
+    }));
+
+    // a non-zero value would make notcurses destroy the widget by itself.
+    c_api::NCRESULT_OK
+}
+
+// The C callback that forwards the end of the reading to the closure.
+pub(super) unsafe extern "C" fn done_cb(
+    _n: *mut NcFdPlane,
+    value: c_int,
+    curry: *mut c_void,
+) -> c_int {
+    let output = &mut *(curry as *mut Output);
+    if let Some(done) = &mut output.done {
+        let _ = catch_unwind(AssertUnwindSafe(|| done(value)));
+    }
+    // a non-zero value would make notcurses destroy the widget by itself.
+    c_api::NCRESULT_OK
+}
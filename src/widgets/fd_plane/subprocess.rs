@@ -0,0 +1,97 @@
+// notcurses::widgets::fd_plane::subprocess
+//
+//!
+//
+
+use crate::{
+    plane::Plane,
+    sys::NcSubproc,
+    widgets::fd_plane::{output::Output, SubprocessBuilder},
+};
+
+/// A scrolling plane showing the output of a subprocess.
+///
+/// Both the standard output and the standard error of the subprocess are
+/// read in a separate thread managed by notcurses, which writes the data on
+/// the plane as it arrives.
+///
+/// When dropped, the subprocess is killed if it's still running.
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # use std::process::Command;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// let mut stdplane = Plane::new(&mut nc)?;
+/// let (exit_tx, exit_rx) = std::sync::mpsc::channel();
+/// let build = Subprocess::builder()
+///     .on_exit(move |status| exit_tx.send(status).unwrap())
+///     .build(&mut stdplane, &Command::new("cargo").arg("--version"))?;
+/// assert![exit_rx.recv().unwrap().success()];
+/// stdplane.render()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Subprocess {
+    pub(super) nc: *mut NcSubproc,
+    pub(super) plane: Plane,
+    pub(super) output: *mut Output,
+}
+
+mod core_impls {
+    use super::Subprocess;
+    use core::fmt;
+
+    impl Drop for Subprocess {
+        fn drop(&mut self) {
+            // kills the subprocess and stops the reading thread
+            // before freeing the state it uses.
+            if crate::Notcurses::is_initialized() {
+                unsafe { crate::sys::c_api::ncsubproc_destroy(self.nc) };
+            }
+            drop(unsafe { Box::from_raw(self.output) });
+        }
+    }
+
+    impl fmt::Debug for Subprocess {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Subprocess {{ {:?} }}", self.plane)
+        }
+    }
+}
+
+/// # constructors
+impl Subprocess {
+    /// Returns a new [`SubprocessBuilder`].
+    pub fn builder() -> SubprocessBuilder {
+        SubprocessBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcSubproc`].
+    pub fn into_ref(&self) -> &NcSubproc {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcSubproc`].
+    pub fn into_ref_mut(&mut self) -> &mut NcSubproc {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl Subprocess {
+    /// Returns a shared reference to the plane where the output is written.
+    pub fn plane(&self) -> &Plane {
+        &self.plane
+    }
+
+    /// Returns an exclusive reference to the plane where the output is written.
+    ///
+    /// Note that the reading thread may be writing on it at the same time.
+    pub fn plane_mut(&mut self) -> &mut Plane {
+        &mut self.plane
+    }
+}
@@ -0,0 +1,190 @@
+// notcurses::widgets::fd_plane::subprocess_builder
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{c_api, NcSubprocOptions},
+    widgets::fd_plane::{
+        output::{done_cb, new_plane, output_cb, Output},
+        Subprocess,
+    },
+    Position, Size,
+};
+use core::{
+    ffi::{c_char, c_void},
+    ptr::null,
+    time::Duration,
+};
+use std::{
+    collections::BTreeMap,
+    ffi::{CString, OsStr, OsString},
+    os::unix::{ffi::OsStrExt, process::ExitStatusExt},
+    process::{Command, ExitStatus},
+};
+
+/// A [`Subprocess`] builder.
+#[derive(Default)]
+pub struct SubprocessBuilder {
+    position: Position,
+    size: Option<Size>,
+    restart_period: Option<Duration>,
+    on_exit: Option<Box<dyn FnMut(ExitStatus) + Send>>,
+}
+
+mod core_impls {
+    use super::SubprocessBuilder;
+    use core::fmt;
+
+    impl fmt::Debug for SubprocessBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("SubprocessBuilder")
+                .field("position", &self.position)
+                .field("size", &self.size)
+                .field("restart_period", &self.restart_period)
+                .field("on_exit", &self.on_exit.is_some())
+                .finish()
+        }
+    }
+}
+
+/// # constructors
+impl SubprocessBuilder {
+    /// Returns a new default `SubprocessBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns the `command` as a new `Subprocess`, drawn on a new child
+    /// plane of the provided `parent`.
+    ///
+    /// The program, arguments and environment of the `command` are used.
+    /// The program is searched in the `PATH`.
+    ///
+    /// # Errors
+    /// - if the `command` has a current directory, which is not supported.
+    /// - if the program, any argument or any environment variable
+    ///   contains a NUL character.
+    /// - if notcurses fails to spawn the subprocess.
+    pub fn build(self, parent: &mut Plane, command: &Command) -> Result<Subprocess> {
+        if command.get_current_dir().is_some() {
+            return Error::msg("The current directory of a `Subprocess` can't be changed.");
+        }
+
+        // the program is also the first argument
+        let program = cstring(command.get_program())?;
+        let mut args = vec![program.clone()];
+        for arg in command.get_args() {
+            args.push(cstring(arg)?);
+        }
+
+        // the inherited environment, with the changes made by the command
+        let mut vars: BTreeMap<OsString, OsString> = std::env::vars_os().collect();
+        for (key, value) in command.get_envs() {
+            match value {
+                Some(value) => vars.insert(key.into(), value.into()),
+                None => vars.remove(key),
+            };
+        }
+        let mut env = vec![];
+        for (key, value) in vars {
+            let mut var = key;
+            var.push("=");
+            var.push(value);
+            env.push(cstring(&var)?);
+        }
+
+        // the arrays of pointers are terminated by a null pointer.
+        let argv: Vec<*const c_char> = args.iter().map(|a| a.as_ptr()).chain([null()]).collect();
+        let envp: Vec<*const c_char> = env.iter().map(|e| e.as_ptr()).chain([null()]).collect();
+
+        let mut plane = new_plane(parent, self.position, self.size)?;
+        let mut on_exit = self.on_exit;
+        let output = Box::into_raw(Box::new(Output {
+            plane: plane.into_ref_mut(),
+            pending: vec![],
+            done: on_exit.take().map(|mut on_exit| {
+                Box::new(move |status| on_exit(ExitStatus::from_raw(status))) as _
+            }),
+        }));
+
+        let options = NcSubprocOptions {
+            curry: output as *mut c_void,
+            // rounded up, so that a sub-second period doesn't disable the restarts.
+            restart_period: self
+                .restart_period
+                .map_or(0, |p| p.as_secs() + u64::from(p.subsec_nanos() > 0)),
+            flags: 0,
+        };
+        let ncsubproc = unsafe {
+            c_api::ncsubproc_createvpe(
+                plane.into_ref_mut(),
+                &options,
+                program.as_ptr(),
+                argv.as_ptr(),
+                envp.as_ptr(),
+                Some(output_cb),
+                Some(done_cb),
+            )
+        };
+
+        if ncsubproc.is_null() {
+            drop(unsafe { Box::from_raw(output) });
+            Error::msg(&format!["Couldn't spawn the `Subprocess` {program:?}."])
+        } else {
+            Ok(Subprocess {
+                nc: ncsubproc,
+                plane,
+                output,
+            })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl SubprocessBuilder {
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *`(0, 0)`*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the size of the plane.
+    ///
+    /// Default: *the rest of the parent plane, from the position*.
+    pub fn size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Restarts the subprocess this long after each time it exits,
+    /// like `watch`.
+    ///
+    /// It has a resolution of seconds, so it's rounded up to whole seconds,
+    /// e.g. 500 milliseconds become 1 second. A zero period doesn't restart it.
+    ///
+    /// Default: *`None`*.
+    pub fn restart_period(mut self, period: Duration) -> Self {
+        self.restart_period = Some(period);
+        self
+    }
+
+    /// Sets a closure called each time the subprocess exits, with its status.
+    ///
+    /// It's called from a thread managed by notcurses.
+    ///
+    /// Default: *`None`*.
+    pub fn on_exit(mut self, on_exit: impl FnMut(ExitStatus) + Send + 'static) -> Self {
+        self.on_exit = Some(Box::new(on_exit));
+        self
+    }
+}
+
+fn cstring(s: &OsStr) -> Result<CString> {
+    CString::new(s.as_bytes())
+        .or_else(|_| Error::msg(&format!["Unexpected NUL character in {s:?}."]))
+}
@@ -0,0 +1,389 @@
+// notcurses::widgets::line_editor::buffer
+//
+//!
+//
+
+use crate::widgets::line_editor::history::History;
+
+// The edited text, with its cursor and the last killed text.
+//
+// It's independent of the drawing, and all indices are char indices.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Buffer {
+    pub text: Vec<char>,
+    // the cursor, as an index into `text`.
+    pub cursor: usize,
+    killed: Vec<char>,
+    multiline: bool,
+}
+
+impl Buffer {
+    pub fn new(multiline: bool) -> Self {
+        Self {
+            multiline,
+            ..Default::default()
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.text.iter().collect()
+    }
+
+    // Returns the cursor position, as a byte index into the text.
+    pub fn cursor_byte(&self) -> usize {
+        self.text[..self.cursor].iter().map(|c| c.len_utf8()).sum()
+    }
+
+    // Returns the char index of the `byte` index into the text, if it's
+    // at a char boundary.
+    pub fn char_index(&self, byte: usize) -> Option<usize> {
+        let mut bytes = 0;
+        for (i, c) in self.text.iter().enumerate() {
+            if bytes >= byte {
+                return (bytes == byte).then_some(i);
+            }
+            bytes += c.len_utf8();
+        }
+        (bytes == byte).then_some(self.text.len())
+    }
+
+    // Replaces the text, and moves the cursor to its end.
+    //
+    // In single-line mode, new lines are replaced by spaces.
+    pub fn replace(&mut self, text: &str) {
+        self.text = text.chars().map(|c| self.filter(c)).collect();
+        self.cursor = self.text.len();
+    }
+
+    // Inserts the `chars` at the cursor, moving it after them.
+    pub fn insert(&mut self, chars: &[char]) {
+        for c in chars {
+            self.text.insert(self.cursor, self.filter(*c));
+            self.cursor += 1;
+        }
+    }
+
+    // Replaces the text between `start` and the cursor with the `chars`,
+    // moving the cursor after them.
+    pub fn splice(&mut self, start: usize, chars: &[char]) {
+        self.text.splice(start..self.cursor, chars.iter().copied());
+        self.cursor = start + chars.len();
+    }
+
+    // Deletes the char before the cursor.
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.text.remove(self.cursor);
+        }
+    }
+
+    // Deletes the char under the cursor.
+    pub fn delete(&mut self) {
+        if self.cursor < self.text.len() {
+            self.text.remove(self.cursor);
+        }
+    }
+
+    // Removes the text between `start` and `end`, keeping it for yanking.
+    pub fn kill(&mut self, start: usize, end: usize) {
+        if start < end {
+            self.killed = self.text.drain(start..end).collect();
+            self.cursor = start;
+        }
+    }
+
+    // Inserts the last killed text at the cursor.
+    pub fn yank(&mut self) {
+        let killed = self.killed.clone();
+        self.insert(&killed);
+    }
+
+    pub fn left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.text.len());
+    }
+
+    // Returns the row and column of the `index` into the text.
+    pub fn row_col(&self, index: usize) -> (usize, usize) {
+        let row = self.text[..index].iter().filter(|c| **c == '\n').count();
+        (row, index - self.line_start(index))
+    }
+
+    pub fn line_start(&self, index: usize) -> usize {
+        self.text[..index]
+            .iter()
+            .rposition(|c| *c == '\n')
+            .map_or(0, |i| i + 1)
+    }
+
+    pub fn line_end(&self, index: usize) -> usize {
+        self.text[index..]
+            .iter()
+            .position(|c| *c == '\n')
+            .map_or(self.text.len(), |i| index + i)
+    }
+
+    // Returns the start of the word before the cursor.
+    pub fn word_start(&self) -> usize {
+        let mut i = self.cursor;
+        while i > 0 && !self.text[i - 1].is_alphanumeric() {
+            i -= 1;
+        }
+        while i > 0 && self.text[i - 1].is_alphanumeric() {
+            i -= 1;
+        }
+        i
+    }
+
+    // Returns the end of the word after the cursor.
+    pub fn word_end(&self) -> usize {
+        let mut i = self.cursor;
+        while i < self.text.len() && !self.text[i].is_alphanumeric() {
+            i += 1;
+        }
+        while i < self.text.len() && self.text[i].is_alphanumeric() {
+            i += 1;
+        }
+        i
+    }
+
+    // Moves to the previous line, or to the previous `history` entry.
+    pub fn up(&mut self, history: &mut History) {
+        let (row, col) = self.row_col(self.cursor);
+        if row > 0 {
+            let start = self.line_start(self.line_start(self.cursor) - 1);
+            self.cursor = (start + col).min(self.line_end(start));
+        } else if let Some(entry) = history.prev(&self.text()) {
+            let entry = entry.to_owned();
+            self.replace(&entry);
+        }
+    }
+
+    // Moves to the next line, or to the next `history` entry.
+    pub fn down(&mut self, history: &mut History) {
+        let (_, col) = self.row_col(self.cursor);
+        let end = self.line_end(self.cursor);
+        if end < self.text.len() {
+            let start = end + 1;
+            self.cursor = (start + col).min(self.line_end(start));
+        } else if let Some(entry) = history.next() {
+            let entry = entry.to_owned();
+            self.replace(&entry);
+        }
+    }
+
+    // Returns the char to store for the typed `c`.
+    fn filter(&self, c: char) -> char {
+        if c == '\n' && !self.multiline {
+            ' '
+        } else {
+            c
+        }
+    }
+}
+
+// Returns the index of the candidate selected after the `selected` one,
+// out of `len`, cycling in either direction.
+pub(super) fn cycle(selected: Option<usize>, len: usize, backwards: bool) -> usize {
+    match (selected, backwards) {
+        (None, false) => 0,
+        (None, true) => len - 1,
+        (Some(i), false) => (i + 1) % len,
+        (Some(i), true) => (i + len - 1) % len,
+    }
+}
+
+// Returns the longest common prefix of all the `strings`.
+pub(super) fn common_prefix(strings: &[String]) -> Vec<char> {
+    let mut prefix: Vec<char> = match strings.first() {
+        Some(first) => first.chars().collect(),
+        None => return vec![],
+    };
+    for s in &strings[1..] {
+        let len = prefix
+            .iter()
+            .zip(s.chars())
+            .take_while(|(a, b)| **a == *b)
+            .count();
+        prefix.truncate(len);
+    }
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{common_prefix, cycle, Buffer, History};
+
+    fn buffer(text: &str, cursor: usize) -> Buffer {
+        let mut buffer = Buffer::new(true);
+        buffer.replace(text);
+        buffer.cursor = cursor;
+        buffer
+    }
+
+    #[test]
+    fn editing() {
+        let mut b = Buffer::new(false);
+        b.insert(&['a', 'ñ', '\n', 'c']);
+        assert_eq!["añ c", b.text()];
+        assert_eq![(4, 5), (b.cursor, b.cursor_byte())];
+
+        b.left();
+        b.left();
+        b.backspace();
+        assert_eq![("a c", 1), (b.text().as_str(), b.cursor)];
+        b.delete();
+        assert_eq![("ac", 1), (b.text().as_str(), b.cursor)];
+        b.right();
+        b.right();
+        b.delete();
+        assert_eq![("ac", 2), (b.text().as_str(), b.cursor)];
+
+        b.replace("x\ny");
+        assert_eq![("x y", 3), (b.text().as_str(), b.cursor)];
+        b.cursor = 0;
+        b.backspace();
+        b.left();
+        assert_eq![("x y", 0), (b.text().as_str(), b.cursor)];
+    }
+
+    #[test]
+    fn byte_indices() {
+        let b = buffer("añb", 2);
+        assert_eq![3, b.cursor_byte()];
+        assert_eq![Some(0), b.char_index(0)];
+        assert_eq![Some(2), b.char_index(3)];
+        assert_eq![None, b.char_index(2)];
+        assert_eq![Some(3), b.char_index(4)];
+        assert_eq![None, b.char_index(5)];
+    }
+
+    #[test]
+    fn words_and_kills() {
+        let mut b = buffer("foo, bar baz", 8);
+        assert_eq![(5, 12), (b.word_start(), b.word_end())];
+        b.cursor = 4;
+        assert_eq![(0, 8), (b.word_start(), b.word_end())];
+
+        b.kill(b.word_start(), b.cursor);
+        assert_eq![(" bar baz", 0), (b.text().as_str(), b.cursor)];
+        b.kill(b.cursor, b.word_end());
+        assert_eq![(" baz", 0), (b.text().as_str(), b.cursor)];
+        b.cursor = 4;
+        b.yank();
+        assert_eq![(" baz bar", 8), (b.text().as_str(), b.cursor)];
+
+        // an empty kill keeps the previous killed text.
+        b.kill(b.cursor, b.line_end(b.cursor));
+        b.yank();
+        assert_eq![" baz bar bar", b.text()];
+    }
+
+    #[test]
+    fn lines() {
+        let mut b = buffer("abc\nd\nefgh", 9);
+        assert_eq![(2, 3), b.row_col(b.cursor)];
+        assert_eq![(6, 10), (b.line_start(9), b.line_end(9))];
+        assert_eq![(4, 5), (b.line_start(4), b.line_end(4))];
+
+        let mut history = History::new(10);
+        // the column is kept when it fits,
+        b.up(&mut history);
+        assert_eq![(1, 1), b.row_col(b.cursor)];
+        b.up(&mut history);
+        assert_eq![(0, 1), b.row_col(b.cursor)];
+        b.down(&mut history);
+        b.down(&mut history);
+        assert_eq![(2, 1), b.row_col(b.cursor)];
+
+        // and killing to the line start doesn't cross lines.
+        b.kill(b.line_start(b.cursor), b.cursor);
+        assert_eq!["abc\nd\nfgh", b.text()];
+    }
+
+    #[test]
+    fn history_navigation() {
+        let mut history = History::new(10);
+        history.push("one");
+        history.push("two");
+        let mut b = buffer("draft", 5);
+
+        // from the first row, the history is browsed back to the oldest entry,
+        b.up(&mut history);
+        assert_eq![("two", 3), (b.text().as_str(), b.cursor)];
+        b.up(&mut history);
+        b.up(&mut history);
+        assert_eq!["one", b.text()];
+
+        // and forth to the draft, after the newest one.
+        b.down(&mut history);
+        assert_eq!["two", b.text()];
+        b.down(&mut history);
+        assert_eq!["draft", b.text()];
+        b.down(&mut history);
+        assert_eq!["draft", b.text()];
+
+        // a multi-line entry is entered at its end, and left from its first row.
+        history.push("a\nb");
+        b.up(&mut history);
+        assert_eq![(1, 1), b.row_col(b.cursor)];
+        b.up(&mut history);
+        assert_eq![("a\nb", 1), (b.text().as_str(), b.cursor)];
+        b.up(&mut history);
+        assert_eq!["two", b.text()];
+    }
+
+    #[test]
+    fn history() {
+        let mut history = History::new(2);
+        history.push("one");
+        history.push("");
+        history.push("one");
+        assert_eq![vec!["one"], history.entries().collect::<Vec<_>>()];
+        history.push("two");
+        history.push("three");
+        assert_eq![vec!["two", "three"], history.entries().collect::<Vec<_>>()];
+
+        assert_eq![None, history.next()];
+        assert_eq![Some("three"), history.prev("draft")];
+        assert_eq![Some("two"), history.prev("ignored")];
+        assert_eq![None, history.prev("ignored")];
+        assert_eq![Some("three"), history.next()];
+        assert_eq![Some("draft"), history.next()];
+        assert_eq![None, history.next()];
+
+        // pushing or resetting stops browsing.
+        history.prev("");
+        history.reset();
+        assert_eq![None, history.next()];
+        assert_eq![Some("three"), history.prev("")];
+
+        let mut disabled = History::new(0);
+        disabled.push("one");
+        assert_eq![None, disabled.prev("")];
+    }
+
+    #[test]
+    fn completion() {
+        let candidates = ["history", "help", "hello"].map(String::from);
+        assert_eq![vec!['h'], common_prefix(&candidates)];
+        assert_eq![vec!['h', 'e', 'l'], common_prefix(&candidates[1..])];
+        assert_eq![Vec::<char>::new(), common_prefix(&[])];
+
+        assert_eq![0, cycle(None, 3, false)];
+        assert_eq![2, cycle(None, 3, true)];
+        assert_eq![0, cycle(Some(2), 3, false)];
+        assert_eq![2, cycle(Some(0), 3, true)];
+
+        // a candidate replaces the text between its start and the cursor.
+        let mut b = buffer("say hel!", 7);
+        b.splice(4, &['h', 'e', 'l', 'l', 'o']);
+        assert_eq![("say hello!", 9), (b.text().as_str(), b.cursor)];
+        b.splice(4, &['h', 'e', 'l', 'p']);
+        assert_eq![("say help!", 8), (b.text().as_str(), b.cursor)];
+    }
+}
@@ -0,0 +1,238 @@
+// notcurses::widgets::line_editor::builder
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::{Plane, Style},
+    widgets::line_editor::{
+        buffer::Buffer, editor::str_width, history::History, Completer, LineEditor,
+    },
+    Position,
+};
+
+/// A [`LineEditor`] builder.
+pub struct LineEditorBuilder {
+    position: Option<Position>,
+    width: Option<u32>,
+    height: u32,
+    prompt: String,
+    text: String,
+    channels: Channels,
+    cursor_channels: Channels,
+    candidate_channels: Channels,
+    selected_channels: Channels,
+    style: Style,
+    max_candidates: u32,
+    history_capacity: usize,
+    completer: Option<Box<dyn Completer>>,
+}
+
+mod core_impls {
+    use super::LineEditorBuilder;
+    use crate::color::Channels;
+    use core::fmt;
+
+    impl Default for LineEditorBuilder {
+        fn default() -> Self {
+            Self {
+                position: None,
+                width: None,
+                height: 1,
+                prompt: String::new(),
+                text: String::new(),
+                channels: Channels::default(),
+                cursor_channels: Channels::from_rgb(0x000000, 0xFFFFFF),
+                candidate_channels: Channels::from_rgb(0xFFFFFF, 0x303030),
+                selected_channels: Channels::from_rgb(0x000000, 0xC0C0C0),
+                style: Default::default(),
+                max_candidates: 8,
+                history_capacity: 100,
+                completer: None,
+            }
+        }
+    }
+
+    impl fmt::Debug for LineEditorBuilder {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("LineEditorBuilder")
+                .field("position", &self.position)
+                .field("width", &self.width)
+                .field("height", &self.height)
+                .field("prompt", &self.prompt)
+                .field("text", &self.text)
+                .field("channels", &self.channels)
+                .field("cursor_channels", &self.cursor_channels)
+                .field("candidate_channels", &self.candidate_channels)
+                .field("selected_channels", &self.selected_channels)
+                .field("style", &self.style)
+                .field("max_candidates", &self.max_candidates)
+                .field("history_capacity", &self.history_capacity)
+                .field("completer", &self.completer.is_some())
+                .finish()
+        }
+    }
+}
+
+/// # constructors
+impl LineEditorBuilder {
+    /// Returns a new default `LineEditorBuilder`.
+    ///
+    /// The editor will be single-line, along the rest of the parent's
+    /// cursor row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `LineEditor` drawn on a new child plane of the provided `parent`.
+    ///
+    /// # Errors
+    /// - if the height is `0`.
+    /// - if the width leaves no room for the text after the prompt.
+    pub fn build(self, parent: &mut Plane) -> Result<LineEditor> {
+        if self.height == 0 {
+            return Error::msg("The height of a `LineEditor` can't be 0.");
+        }
+        let position = self
+            .position
+            .unwrap_or_else(|| Position::new(parent.cursor().x(), parent.cursor().y()));
+        let width = self.width.unwrap_or_else(|| {
+            let parent_width: u32 = parent.size().w() as u32;
+            parent_width.saturating_sub(position.x() as u32)
+        });
+        let prompt: Vec<char> = self.prompt.chars().collect();
+        if width as usize <= str_width(&prompt) {
+            return Error::msg("The width of a `LineEditor` must be greater than its prompt.");
+        }
+
+        let plane = Plane::builder()
+            .position(position)
+            .size((width, self.height))
+            .build_child(parent)?;
+
+        let mut editor = LineEditor {
+            plane,
+            prompt,
+            multiline: self.height > 1,
+            channels: self.channels,
+            cursor_channels: self.cursor_channels,
+            candidate_channels: self.candidate_channels,
+            selected_channels: self.selected_channels,
+            style: self.style,
+            max_candidates: self.max_candidates,
+            history: History::new(self.history_capacity),
+            completer: self.completer,
+            buffer: Buffer::new(self.height > 1),
+            scroll: (0, 0),
+            completion: None,
+        };
+        editor.set_text(&self.text)?;
+        Ok(editor)
+    }
+}
+
+/// # methods (chainable)
+impl LineEditorBuilder {
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *the parent's cursor*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// Sets the width, including the prompt.
+    ///
+    /// Default: *the rest of the parent plane, from the position*.
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the height. If it's greater than `1` the editor is multi-line.
+    ///
+    /// Default: *`1`*.
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the prompt, shown before the text.
+    ///
+    /// Default: *`""`*.
+    pub fn prompt(mut self, prompt: &str) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the initial text.
+    ///
+    /// Default: *`""`*.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Sets the channels of the text and the prompt.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the cell under the cursor.
+    ///
+    /// Default: *black over white*.
+    pub fn cursor_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.cursor_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the completion candidates, and of the selected one.
+    ///
+    /// Default: *white over dark grey, and black over light grey*.
+    pub fn candidate_channels(
+        mut self,
+        candidates: impl Into<Channels>,
+        selected: impl Into<Channels>,
+    ) -> Self {
+        self.candidate_channels = candidates.into();
+        self.selected_channels = selected.into();
+        self
+    }
+
+    /// Sets the text style.
+    ///
+    /// Default: *[`Style::None`]*.
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the maximum number of completion candidates shown at once.
+    ///
+    /// Default: *`8`*.
+    pub fn max_candidates(mut self, max_candidates: u32) -> Self {
+        self.max_candidates = max_candidates;
+        self
+    }
+
+    /// Sets the maximum number of entries in the history, or `0` to disable it.
+    ///
+    /// Default: *`100`*.
+    pub fn history_capacity(mut self, capacity: usize) -> Self {
+        self.history_capacity = capacity;
+        self
+    }
+
+    /// Sets the `completer`, called when pressing [`Tab`][crate::Key::Tab].
+    ///
+    /// Default: *`None`*.
+    pub fn completer(mut self, completer: impl Completer + 'static) -> Self {
+        self.completer = Some(Box::new(completer));
+        self
+    }
+}
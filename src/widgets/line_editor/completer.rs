@@ -0,0 +1,40 @@
+// notcurses::widgets::line_editor::completer
+//
+//!
+//
+
+/// Provides completion candidates to a [`LineEditor`][crate::LineEditor].
+///
+/// It's implemented for closures with the same signature as [`complete`].
+///
+/// [`complete`]: Completer#tymethod.complete
+///
+/// # Example
+/// ```
+/// # use notcurses::Completer;
+/// let commands = ["help", "history", "quit"];
+/// let mut completer = move |text: &str, cursor: usize| -> (usize, Vec<String>) {
+///     let word = &text[..cursor];
+///     let candidates = commands
+///         .iter()
+///         .filter(|c| c.starts_with(word))
+///         .map(|c| c.to_string())
+///         .collect();
+///     (0, candidates)
+/// };
+/// assert_eq![completer.complete("h", 1), (0, vec!["help".into(), "history".into()])];
+/// ```
+pub trait Completer {
+    /// Returns the candidates for completing the `text` at the `cursor`.
+    ///
+    /// The `cursor` is a byte index into the `text`. The returned index is
+    /// where the candidates start, so that the text between it and the
+    /// cursor will be replaced by the chosen candidate.
+    fn complete(&mut self, text: &str, cursor: usize) -> (usize, Vec<String>);
+}
+
+impl<F: FnMut(&str, usize) -> (usize, Vec<String>)> Completer for F {
+    fn complete(&mut self, text: &str, cursor: usize) -> (usize, Vec<String>) {
+        self(text, cursor)
+    }
+}
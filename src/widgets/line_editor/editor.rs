@@ -0,0 +1,469 @@
+// notcurses::widgets::line_editor::editor
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::NotcursesResult as Result,
+    input::{Input, Key, KeyMod, Received},
+    plane::{Plane, Style},
+    sys::c_api,
+    widgets::line_editor::{
+        buffer::{self, Buffer},
+        history::History,
+        Completer, LineEditorBuilder,
+    },
+};
+use std::ffi::CString;
+
+/// An editable line of text, with history and completion.
+///
+/// It's drawn on its own plane, with the prompt at the start of the first row.
+/// When the text doesn't fit, it scrolls horizontally, and vertically in
+/// multi-line mode.
+///
+/// # Key bindings
+///
+/// | keys | action |
+/// |------|--------|
+/// | `Left`, `Ctrl+B` / `Right`, `Ctrl+F` | move one character |
+/// | `Ctrl+Left`, `Alt+B` / `Ctrl+Right`, `Alt+F` | move one word |
+/// | `Home`, `Ctrl+A` / `End`, `Ctrl+E` | move to the start / end of the line |
+/// | `Backspace`, `Ctrl+H` / `Del`, `Ctrl+D` | delete one character |
+/// | `Ctrl+W`, `Alt+Backspace` / `Alt+D` | kill the previous / next word |
+/// | `Ctrl+U` / `Ctrl+K` | kill to the start / end of the line |
+/// | `Ctrl+Y` | yank the last killed text |
+/// | `Up`, `Ctrl+P` / `Down`, `Ctrl+N` | move between lines, or browse the history |
+/// | `Tab` / `Shift+Tab` | complete, or cycle the candidates |
+/// | `Esc` | hide the candidates |
+/// | `Alt+Enter` | insert a new line, in multi-line mode |
+/// | `Enter` | submit the text |
+///
+/// # Example
+/// ```ignore
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let mut nc = Notcurses::new_cli()?;
+/// let mut cli = nc.cli_plane()?;
+/// let mut editor = LineEditor::builder().prompt("> ").build(&mut cli)?;
+/// loop {
+///     cli.render()?;
+///     let input = nc.get_event()?;
+///     if input.is_char('c') && input.keymod.has_ctrl() {
+///         break;
+///     }
+///     if let Some(LineEditorAction::Submitted(line)) = editor.offer_input(&input)? {
+///         println!("{line}");
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct LineEditor {
+    pub(super) plane: Plane,
+    pub(super) prompt: Vec<char>,
+    pub(super) multiline: bool,
+    pub(super) channels: Channels,
+    pub(super) cursor_channels: Channels,
+    pub(super) candidate_channels: Channels,
+    pub(super) selected_channels: Channels,
+    pub(super) style: Style,
+    pub(super) max_candidates: u32,
+    pub(super) history: History,
+    pub(super) completer: Option<Box<dyn Completer>>,
+
+    pub(super) buffer: Buffer,
+    // the first visible column, in cells, and row of the text.
+    pub(super) scroll: (usize, usize),
+    pub(super) completion: Option<Completion>,
+}
+
+// The state of an ongoing completion, with the candidates shown in a child plane.
+pub(super) struct Completion {
+    plane: Plane,
+    // where the candidates start, as an index into `text`.
+    start: usize,
+    candidates: Vec<String>,
+    selected: Option<usize>,
+}
+
+/// The result of offering an [`Input`] to a [`LineEditor`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LineEditorAction {
+    /// The input was consumed by the editor (e.g. for editing).
+    Consumed,
+
+    /// The text was submitted by pressing [`Enter`][Key::Enter].
+    ///
+    /// The editor is cleared, and the text is added to the history.
+    Submitted(String),
+}
+
+mod core_impls {
+    use super::LineEditor;
+    use core::fmt;
+
+    impl fmt::Debug for LineEditor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "LineEditor {{ text: {:?}, cursor: {}, multiline: {} }}",
+                self.text(),
+                self.buffer.cursor,
+                self.multiline
+            )
+        }
+    }
+}
+
+/// # constructors
+impl LineEditor {
+    /// Returns a new [`LineEditorBuilder`].
+    pub fn builder() -> LineEditorBuilder {
+        LineEditorBuilder::new()
+    }
+}
+
+/// # methods
+impl LineEditor {
+    /// Returns the current text.
+    pub fn text(&self) -> String {
+        self.buffer.text()
+    }
+
+    /// Replaces the text, moves the cursor to its end, and redraws.
+    ///
+    /// In single-line mode, new lines are replaced by spaces.
+    pub fn set_text(&mut self, text: &str) -> Result<()> {
+        self.close_completion();
+        self.history.reset();
+        self.buffer.replace(text);
+        self.redraw()
+    }
+
+    /// Clears the text, and redraws.
+    pub fn clear(&mut self) -> Result<()> {
+        self.set_text("")
+    }
+
+    /// Returns the cursor position, as a byte index into the text.
+    pub fn cursor(&self) -> usize {
+        self.buffer.cursor_byte()
+    }
+
+    /// Returns the entries of the history, from the oldest one.
+    pub fn history(&self) -> impl Iterator<Item = &str> {
+        self.history.entries()
+    }
+
+    /// Adds an `entry` to the history, unless it's empty or repeats the newest one.
+    pub fn push_history(&mut self, entry: &str) {
+        self.history.push(entry);
+    }
+
+    /// Returns a shared reference to the plane of the editor.
+    pub fn plane(&self) -> &Plane {
+        &self.plane
+    }
+
+    /// Returns an exclusive reference to the plane of the editor.
+    pub fn plane_mut(&mut self) -> &mut Plane {
+        &mut self.plane
+    }
+
+    /// Offers an `input` to the editor, and redraws it if needed.
+    ///
+    /// Returns `None` if the input was not relevant to the editor.
+    pub fn offer_input(&mut self, input: &Input) -> Result<Option<LineEditorAction>> {
+        if input.is_release() || !input.received() {
+            return Ok(None);
+        }
+        // the locks shouldn't change the meaning of the keys.
+        let keymod = input.keymod & !(KeyMod::CapsLock | KeyMod::NumLock);
+        let (ctrl, alt) = (keymod.has_ctrl(), keymod.has_alt() || keymod.has_meta());
+        let plain = !ctrl && !alt;
+        let ctrl_char = |c: char| ctrl && !alt && is_char_ignore_case(input, c);
+        let alt_char = |c: char| alt && !ctrl && is_char_ignore_case(input, c);
+
+        // completion
+        if is_key_or_char(input, Key::Tab, '\t') && !ctrl && !alt {
+            return self.complete(keymod.has_shift());
+        }
+        if self.completion.is_some() {
+            self.close_completion();
+            if is_key_or_char(input, Key::Esc, '\x1b') {
+                self.redraw()?;
+                return Ok(Some(LineEditorAction::Consumed));
+            }
+        }
+
+        // submission and new lines
+        if is_key_or_char(input, Key::Enter, '\n') || input.is_char('\r') {
+            if alt && self.multiline {
+                self.buffer.insert(&['\n']);
+            } else if plain {
+                let text = self.text();
+                self.history.push(&text);
+                self.buffer.replace("");
+                self.redraw()?;
+                return Ok(Some(LineEditorAction::Submitted(text)));
+            } else {
+                return Ok(None);
+            }
+        }
+        // movement
+        else if (input.is_key(Key::Left) && plain) || ctrl_char('b') {
+            self.buffer.left();
+        } else if (input.is_key(Key::Right) && plain) || ctrl_char('f') {
+            self.buffer.right();
+        } else if (input.is_key(Key::Left) && ctrl) || alt_char('b') {
+            self.buffer.cursor = self.buffer.word_start();
+        } else if (input.is_key(Key::Right) && ctrl) || alt_char('f') {
+            self.buffer.cursor = self.buffer.word_end();
+        } else if input.is_key(Key::Home) || ctrl_char('a') {
+            self.buffer.cursor = self.buffer.line_start(self.buffer.cursor);
+        } else if input.is_key(Key::End) || ctrl_char('e') {
+            self.buffer.cursor = self.buffer.line_end(self.buffer.cursor);
+        } else if input.is_key(Key::Up) || ctrl_char('p') {
+            self.buffer.up(&mut self.history);
+        } else if input.is_key(Key::Down) || ctrl_char('n') {
+            self.buffer.down(&mut self.history);
+        }
+        // deletion
+        else if (is_key_or_char(input, Key::Backspace, '\x08') && !alt) || ctrl_char('h') {
+            self.buffer.backspace();
+        } else if input.is_key(Key::Del) || ctrl_char('d') {
+            self.buffer.delete();
+        } else if ctrl_char('w') || (input.is_key(Key::Backspace) && alt) {
+            let b = &mut self.buffer;
+            b.kill(b.word_start(), b.cursor);
+        } else if alt_char('d') {
+            let b = &mut self.buffer;
+            b.kill(b.cursor, b.word_end());
+        } else if ctrl_char('u') {
+            let b = &mut self.buffer;
+            b.kill(b.line_start(b.cursor), b.cursor);
+        } else if ctrl_char('k') {
+            let b = &mut self.buffer;
+            b.kill(b.cursor, b.line_end(b.cursor));
+        } else if ctrl_char('y') {
+            self.buffer.yank();
+        }
+        // insertion
+        else if let Received::Char(c) = input.received {
+            if !plain || c.is_control() {
+                return Ok(None);
+            }
+            self.buffer.insert(&[c]);
+        } else {
+            return Ok(None);
+        }
+
+        if !input.is_key(Key::Up) && !input.is_key(Key::Down) && !ctrl_char('p') && !ctrl_char('n')
+        {
+            self.history.reset();
+        }
+        self.redraw()?;
+        Ok(Some(LineEditorAction::Consumed))
+    }
+
+    /// Redraws the editor, and the completion candidates if they're shown.
+    pub fn redraw(&mut self) -> Result<()> {
+        let (width, height): (u32, u32) = self.plane.size().into();
+        let (width, height) = (width as usize, height as usize);
+        let prompt_width = str_width(&self.prompt);
+        let area_width = width.saturating_sub(prompt_width).max(1);
+
+        // keep the cursor visible, measuring the columns in cells
+        let text = &self.buffer.text;
+        let cursor = self.buffer.cursor;
+        let (row, _) = self.buffer.row_col(cursor);
+        let cursor_x = str_width(&text[self.buffer.line_start(cursor)..cursor]);
+        let under = match text.get(cursor) {
+            Some(c) if *c != '\n' => *c,
+            _ => ' ',
+        };
+        let under_width = char_width(under).max(1);
+        let (sx, sy) = &mut self.scroll;
+        if cursor_x < *sx {
+            *sx = cursor_x;
+        } else if cursor_x + under_width > *sx + area_width {
+            *sx = cursor_x + under_width - area_width;
+        }
+        if row < *sy {
+            *sy = row;
+        } else if row >= *sy + height {
+            *sy = row + 1 - height;
+        }
+        let (sx, sy) = self.scroll;
+
+        self.plane.erase();
+        self.plane.set_base(" ", self.style, self.channels)?;
+        self.plane.set_channels(self.channels);
+        self.plane.set_styles(self.style);
+
+        for (y, line) in text.split(|c| *c == '\n').enumerate().skip(sy).take(height) {
+            let y = (y - sy) as u32;
+            if y == 0 && sy == 0 {
+                let prompt: String = self.prompt.iter().collect();
+                self.plane.putstr_at((0, y), &prompt)?;
+            }
+            // only the chars that fit entirely are drawn.
+            let (mut x, mut first_x, mut visible) = (0, None, String::new());
+            for c in line {
+                let w = char_width(*c);
+                if x >= sx && x + w <= sx + area_width {
+                    first_x.get_or_insert(x);
+                    visible.push(*c);
+                }
+                x += w;
+            }
+            if let Some(first_x) = first_x {
+                let x = prompt_width + first_x - sx;
+                self.plane.putstr_at((x as u32, y), &visible)?;
+            }
+        }
+
+        // the cursor is drawn with its own channels
+        let cursor_pos = ((prompt_width + cursor_x - sx) as u32, (row - sy) as u32);
+        self.plane.set_channels(self.cursor_channels);
+        self.plane.putstr_at(cursor_pos, &under.to_string())?;
+        self.plane.set_channels(self.channels);
+
+        self.draw_completion(cursor_pos.1)
+    }
+}
+
+impl LineEditor {
+    // Completes the text at the cursor, or cycles the shown candidates.
+    fn complete(&mut self, backwards: bool) -> Result<Option<LineEditorAction>> {
+        if let Some(completion) = &mut self.completion {
+            let selected =
+                buffer::cycle(completion.selected, completion.candidates.len(), backwards);
+            completion.selected = Some(selected);
+            let candidate: Vec<char> = completion.candidates[selected].chars().collect();
+            self.buffer.splice(completion.start, &candidate);
+            self.redraw()?;
+            return Ok(Some(LineEditorAction::Consumed));
+        }
+
+        let (text, cursor) = (self.text(), self.cursor());
+        let (start, mut candidates) = match &mut self.completer {
+            Some(completer) => completer.complete(&text, cursor),
+            None => return Ok(None),
+        };
+        // convert the byte index into a char index
+        let start = self
+            .buffer
+            .char_index(start.min(cursor))
+            .unwrap_or(self.buffer.cursor);
+
+        candidates.dedup();
+        let common = buffer::common_prefix(&candidates);
+        if common.len() > self.buffer.cursor - start {
+            self.buffer.splice(start, &common);
+        }
+        if candidates.len() > 1 {
+            let plane = self.plane.new_child()?;
+            self.completion = Some(Completion {
+                plane,
+                start,
+                candidates,
+                selected: None,
+            });
+        }
+        self.redraw()?;
+        Ok(Some(LineEditorAction::Consumed))
+    }
+
+    fn close_completion(&mut self) {
+        self.completion = None;
+    }
+
+    // Draws the completion candidates below the cursor row, aligned with their start.
+    fn draw_completion(&mut self, cursor_row: u32) -> Result<()> {
+        let (start_x, sx) = match &self.completion {
+            Some(completion) => {
+                let line_start = self.buffer.line_start(completion.start);
+                let start_x = str_width(&self.buffer.text[line_start..completion.start]);
+                (start_x, self.scroll.0)
+            }
+            None => return Ok(()),
+        };
+        let x = (str_width(&self.prompt) + start_x.saturating_sub(sx)) as i32;
+        let completion = self.completion.as_mut().expect("checked above");
+
+        let width = completion
+            .candidates
+            .iter()
+            .map(|c| str_width(&c.chars().collect::<Vec<_>>()))
+            .max()
+            .unwrap_or(1)
+            .max(1) as u32;
+        let len = completion.candidates.len() as u32;
+        let height = len.min(self.max_candidates.max(1));
+
+        // scroll the candidates to keep the selected one visible
+        let selected = completion.selected.unwrap_or(0) as u32;
+        let first = selected.saturating_sub(height - 1);
+
+        let plane = &mut completion.plane;
+        plane.resize_simple((width, height))?;
+        plane.move_to((x, cursor_row as i32 + 1))?;
+        plane.erase();
+        plane.set_base(" ", self.style, self.candidate_channels)?;
+        plane.set_styles(self.style);
+        for (y, candidate) in completion
+            .candidates
+            .iter()
+            .enumerate()
+            .skip(first as usize)
+            .take(height as usize)
+        {
+            let channels = if Some(y) == completion.selected {
+                self.selected_channels
+            } else {
+                self.candidate_channels
+            };
+            plane.set_channels(channels);
+            let padding = width as usize - str_width(&candidate.chars().collect::<Vec<_>>());
+            let padded = format!["{candidate}{:padding$}", ""];
+            plane.putstr_at((0, y as u32 - first), &padded)?;
+        }
+        plane.move_top();
+        Ok(())
+    }
+}
+
+// Returns `true` if the input is the `key`, or its equivalent `character`.
+fn is_key_or_char(input: &Input, key: Key, character: char) -> bool {
+    input.is_key(key) || input.is_char(character)
+}
+
+// Returns `true` if the input is the `character`, in any case.
+fn is_char_ignore_case(input: &Input, character: char) -> bool {
+    matches!(input.received, Received::Char(c) if c.eq_ignore_ascii_case(&character))
+}
+
+// Returns the number of columns occupied by the `chars`.
+pub(super) fn str_width(chars: &[char]) -> usize {
+    chars.iter().map(|c| char_width(*c)).sum()
+}
+
+// Returns the number of columns occupied by the `character`,
+// which is `0` for combining characters, and `1` for control characters.
+fn char_width(character: char) -> usize {
+    let cstring = match CString::new(character.to_string()) {
+        Ok(cstring) => cstring,
+        Err(_) => return 1,
+    };
+    let width = unsafe {
+        c_api::ncstrwidth(
+            cstring.as_ptr(),
+            core::ptr::null_mut(),
+            core::ptr::null_mut(),
+        )
+    };
+    usize::try_from(width).unwrap_or(1)
+}
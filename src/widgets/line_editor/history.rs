@@ -0,0 +1,79 @@
+// notcurses::widgets::line_editor::history
+//
+//!
+//
+
+use std::collections::VecDeque;
+
+// An in-memory ring of submitted entries, browsable from the newest one.
+#[derive(Clone, Debug, Default)]
+pub(super) struct History {
+    entries: VecDeque<String>,
+    capacity: usize,
+    // the index of the entry being browsed, if any.
+    index: Option<usize>,
+    // the text being edited before starting to browse.
+    draft: String,
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|s| s.as_str())
+    }
+
+    // Adds a new entry, unless it's empty or repeats the newest one,
+    // and stops browsing.
+    pub fn push(&mut self, entry: &str) {
+        self.index = None;
+        if self.capacity == 0
+            || entry.is_empty()
+            || self.entries.back().map(|s| s.as_str()) == Some(entry)
+        {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry.into());
+    }
+
+    // Returns the previous (older) entry, saving the `current` text when
+    // starting to browse.
+    pub fn prev(&mut self, current: &str) -> Option<&str> {
+        let index = match self.index {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.into();
+                self.entries.len() - 1
+            }
+            Some(0) => return None,
+            Some(i) => i - 1,
+        };
+        self.index = Some(index);
+        Some(&self.entries[index])
+    }
+
+    // Returns the next (newer) entry, or the saved draft after the newest one.
+    pub fn next(&mut self) -> Option<&str> {
+        let index = self.index?;
+        if index + 1 < self.entries.len() {
+            self.index = Some(index + 1);
+            Some(&self.entries[index + 1])
+        } else {
+            self.index = None;
+            Some(&self.draft)
+        }
+    }
+
+    // Stops browsing.
+    pub fn reset(&mut self) {
+        self.index = None;
+    }
+}
@@ -0,0 +1,14 @@
+// notcurses::widgets::line_editor
+//
+//!
+//
+
+mod buffer;
+mod builder;
+mod completer;
+mod editor;
+mod history;
+
+pub use builder::LineEditorBuilder;
+pub use completer::Completer;
+pub use editor::{LineEditor, LineEditorAction};
@@ -0,0 +1,158 @@
+// notcurses::widgets::menu::builder
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{
+        widgets::{NcMenu, NcMenuItem, NcMenuOptions, NcMenuSection},
+        NcInput,
+    },
+    widgets::menu::{Menu, MenuSection},
+};
+use std::ffi::CString;
+
+/// A [`Menu`] builder.
+#[derive(Clone, Debug, Default)]
+pub struct MenuBuilder {
+    sections: Vec<MenuSection>,
+    header_channels: Channels,
+    section_channels: Channels,
+    bottom: bool,
+    hiding: bool,
+}
+
+/// # constructors
+impl MenuBuilder {
+    /// Returns a new default `MenuBuilder`, without sections.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `Menu` drawn on a new child plane of the provided `parent`.
+    ///
+    /// # Errors
+    /// - if there are no sections.
+    /// - if any section that is not a separator has no items.
+    /// - if any name or label contains a NUL character.
+    pub fn build(self, parent: &mut Plane) -> Result<Menu> {
+        if self.sections.is_empty() {
+            return Error::msg("A `Menu` must have at least one section.");
+        }
+
+        // the strings must live until the menu has been created, since
+        // notcurses makes its own copy of them.
+        let mut strings: Vec<CString> = vec![];
+        let mut cstring = |s: &str| -> Result<*const _> {
+            if s.is_empty() {
+                Ok(core::ptr::null())
+            } else {
+                let cs = CString::new(s).or_else(|_| Error::msg("Unexpected NUL character."))?;
+                let ptr = cs.as_ptr();
+                strings.push(cs);
+                Ok(ptr)
+            }
+        };
+
+        let mut nc_items: Vec<Vec<NcMenuItem>> = vec![];
+        for section in &self.sections {
+            if !section.is_separator() && section.items.is_empty() {
+                return Error::msg("A `MenuSection` must have at least one item.");
+            }
+            let mut items = vec![];
+            for item in &section.items {
+                items.push(NcMenuItem {
+                    desc: cstring(&item.label)?,
+                    shortcut: NcInput::from(item.shortcut),
+                });
+            }
+            nc_items.push(items);
+        }
+
+        let mut nc_sections: Vec<NcMenuSection> = vec![];
+        for (section, items) in self.sections.iter().zip(nc_items.iter_mut()) {
+            if section.is_separator() {
+                nc_sections.push(NcMenuSection::new_separator());
+            } else {
+                nc_sections.push(NcMenuSection {
+                    name: cstring(&section.name)?,
+                    itemcount: items.len() as i32,
+                    items: items.as_mut_ptr(),
+                    shortcut: NcInput::from(section.shortcut),
+                });
+            }
+        }
+
+        let mut flags = 0;
+        if self.bottom {
+            flags |= NcMenuOptions::BOTTOM;
+        }
+        if self.hiding {
+            flags |= NcMenuOptions::HIDING;
+        }
+        let options = NcMenuOptions::with_all_args(
+            &mut nc_sections,
+            self.header_channels,
+            self.section_channels,
+            flags,
+        );
+
+        let ncmenu = NcMenu::new(parent.into_ref_mut(), &options)?;
+
+        Ok(Menu {
+            nc: ncmenu,
+            sections: self.sections,
+            disabled: vec![],
+        })
+    }
+}
+
+/// # methods (chainable)
+impl MenuBuilder {
+    /// Adds a `section` after the existing ones.
+    pub fn section(mut self, section: MenuSection) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Sets all the `sections`, replacing the existing ones.
+    pub fn sections(mut self, sections: Vec<MenuSection>) -> Self {
+        self.sections = sections;
+        self
+    }
+
+    /// Sets the channels of the header.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn header_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.header_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the sections.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn section_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.section_channels = channels.into();
+        self
+    }
+
+    /// If `true`, the menu will be placed at the bottom of the parent plane.
+    ///
+    /// Default: *`false`* (placed at the top).
+    pub fn bottom(mut self, bottom: bool) -> Self {
+        self.bottom = bottom;
+        self
+    }
+
+    /// If `true`, the menu will only be visible while it's unrolled.
+    ///
+    /// Default: *`false`*.
+    pub fn hiding(mut self, hiding: bool) -> Self {
+        self.hiding = hiding;
+        self
+    }
+}
@@ -0,0 +1,264 @@
+// notcurses::widgets::menu::menu
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::{Input, Key, KeyMod},
+    sys::widgets::NcMenu,
+    widgets::menu::{MenuBuilder, MenuItem, MenuSection},
+};
+
+/// A menu bar, with unrollable [`MenuSection`]s.
+///
+/// It's drawn on its own plane, which is destroyed together with the menu.
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// let mut nc = Notcurses::headless((80, 24))?;
+/// let mut plane = Plane::new(&mut nc)?;
+/// let mut menu = Menu::builder()
+///     .section(MenuSection::new(
+///         "File",
+///         vec![
+///             MenuItem::new("Open", Input::new('o', KeyMod::Ctrl)),
+///             MenuItem::new("Quit", Input::new('q', KeyMod::Ctrl)),
+///         ],
+///         Input::new('f', KeyMod::Alt),
+///     ))
+///     .build(&mut plane)?;
+///
+/// let quit = menu.sections()[0].items[1].clone();
+/// assert_eq![
+///     Some(MenuAction::Selected(quit)),
+///     menu.offer_input(&Input::new('q', KeyMod::Ctrl))
+/// ];
+/// # Ok(())
+/// # }
+/// ```
+pub struct Menu {
+    pub(super) nc: *mut NcMenu,
+    pub(super) sections: Vec<MenuSection>,
+    // (section, item) names of the disabled items.
+    pub(super) disabled: Vec<(String, String)>,
+}
+
+/// The result of offering an [`Input`] to a [`Menu`].
+///
+/// # Used by
+/// - [`Menu.offer_input`][Menu#method.offer_input]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MenuAction {
+    /// The input was consumed by the menu (e.g. for navigation).
+    Consumed,
+
+    /// An item was selected, either by its shortcut, by pressing
+    /// [`Enter`][Key::Enter] over it, or by clicking over it.
+    Selected(MenuItem),
+}
+
+mod core_impls {
+    use super::Menu;
+    use core::fmt;
+
+    impl Drop for Menu {
+        fn drop(&mut self) {
+            if crate::Notcurses::is_initialized() {
+                self.into_ref_mut().destroy();
+            }
+        }
+    }
+
+    impl fmt::Debug for Menu {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let names: Vec<_> = self.sections.iter().map(|s| s.name.as_str()).collect();
+            write!(
+                f,
+                "Menu {{ sections: {names:?} disabled: {:?} }}",
+                self.disabled
+            )
+        }
+    }
+}
+
+/// # constructors
+impl Menu {
+    /// Returns a new [`MenuBuilder`].
+    pub fn builder() -> MenuBuilder {
+        MenuBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcMenu`].
+    pub fn into_ref(&self) -> &NcMenu {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcMenu`].
+    pub fn into_ref_mut(&mut self) -> &mut NcMenu {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl Menu {
+    /// Returns the sections of the menu.
+    pub fn sections(&self) -> &[MenuSection] {
+        &self.sections
+    }
+
+    /// Offers an `input` to the menu.
+    ///
+    /// Returns `None` if the input was not relevant to the menu.
+    pub fn offer_input(&mut self, input: &Input) -> Option<MenuAction> {
+        if input.is_release() {
+            // a mouse click over an unrolled item
+            if input.is_key(Key::Button1) {
+                let label = self.into_ref().mouse_selected(input.into(), None).ok();
+                if let Some(item) = label.and_then(|l| self.find_enabled(&l)) {
+                    let _ = self.rollup();
+                    return Some(MenuAction::Selected(item));
+                }
+            }
+        } else {
+            // enter over an unrolled item, regardless of the locks
+            let keymod = input.keymod & !(KeyMod::CapsLock | KeyMod::NumLock);
+            if input.is_key(Key::Enter) && keymod.has_none() {
+                if let Some(item) = self.selected() {
+                    let _ = self.rollup();
+                    return Some(MenuAction::Selected(item));
+                }
+            }
+            // an item shortcut
+            if let Some(item) = self.find_shortcut(input) {
+                let _ = self.rollup();
+                return Some(MenuAction::Selected(item));
+            }
+        }
+
+        // section shortcuts, navigation, and mouse clicks over the header
+        if self.into_ref_mut().offer_input(input.into()) {
+            Some(MenuAction::Consumed)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the currently highlighted item, if a section is unrolled
+    /// and the item is enabled.
+    pub fn selected(&mut self) -> Option<MenuItem> {
+        let label = self.into_ref_mut().selected(None)?;
+        self.find_enabled(&label)
+    }
+
+    /// Rolls up any unrolled section.
+    pub fn rollup(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().rollup()?)
+    }
+
+    /// Unrolls the section with the provided `index`.
+    ///
+    /// # Errors
+    /// - if the index is invalid, or corresponds to a separator.
+    pub fn unroll(&mut self, index: u32) -> Result<()> {
+        Ok(self.into_ref_mut().unroll(index)?)
+    }
+
+    /// Unrolls the next section, wrapping around.
+    pub fn next_section(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().nextsection()?)
+    }
+
+    /// Unrolls the previous section, wrapping around.
+    pub fn prev_section(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().prevsection()?)
+    }
+
+    /// Highlights the next item in the unrolled section, wrapping around.
+    pub fn next_item(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().nextitem()?)
+    }
+
+    /// Highlights the previous item in the unrolled section, wrapping around.
+    pub fn prev_item(&mut self) -> Result<()> {
+        Ok(self.into_ref_mut().previtem()?)
+    }
+
+    /// Returns `true` if the `item` of the `section` is enabled.
+    ///
+    /// Returns `false` if there's no such item.
+    pub fn is_item_enabled(&self, section: &str, item: &str) -> bool {
+        self.find(section, item).is_some() && !self.is_disabled(section, item)
+    }
+
+    /// Enables or disables the `item` of the `section`.
+    ///
+    /// Disabled items can't be selected.
+    ///
+    /// # Errors
+    /// - if there's no such item.
+    pub fn set_item_enabled(&mut self, section: &str, item: &str, enabled: bool) -> Result<()> {
+        if self.find(section, item).is_none() {
+            return Error::msg(&format![
+                "There's no {item:?} item in the {section:?} section."
+            ]);
+        }
+        self.into_ref_mut()
+            .item_set_status(section, item, enabled)?;
+
+        self.disabled.retain(|(s, i)| !(s == section && i == item));
+        if !enabled {
+            self.disabled.push((section.into(), item.into()));
+        }
+        Ok(())
+    }
+}
+
+// private methods
+impl Menu {
+    fn find(&self, section: &str, item: &str) -> Option<&MenuItem> {
+        self.sections
+            .iter()
+            .filter(|s| !s.is_separator() && s.name == section)
+            .flat_map(|s| s.items.iter())
+            .find(|i| !i.is_separator() && i.label == item)
+    }
+
+    fn is_disabled(&self, section: &str, item: &str) -> bool {
+        self.disabled.iter().any(|(s, i)| s == section && i == item)
+    }
+
+    // Returns the first enabled item with the provided `label`.
+    fn find_enabled(&self, label: &str) -> Option<MenuItem> {
+        self.enabled_items().find(|i| i.label == label)
+    }
+
+    // Returns the first enabled item with the provided `input` as its shortcut.
+    fn find_shortcut(&self, input: &Input) -> Option<MenuItem> {
+        if !input.received() {
+            return None;
+        }
+        // the locks shouldn't prevent shortcuts from working.
+        let locks = KeyMod::CapsLock | KeyMod::NumLock;
+        self.enabled_items().find(|i| {
+            i.shortcut.received == input.received
+                && (i.shortcut.keymod & !locks) == (input.keymod & !locks)
+        })
+    }
+
+    fn enabled_items(&self) -> impl Iterator<Item = MenuItem> + '_ {
+        self.sections
+            .iter()
+            .filter(|s| !s.is_separator())
+            .flat_map(move |s| {
+                s.items
+                    .iter()
+                    .filter(move |i| !i.is_separator() && !self.is_disabled(&s.name, &i.label))
+            })
+            .cloned()
+    }
+}
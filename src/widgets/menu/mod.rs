@@ -0,0 +1,12 @@
+// notcurses::widgets::menu
+//
+//!
+//
+
+mod builder;
+mod menu;
+mod section;
+
+pub use builder::MenuBuilder;
+pub use menu::{Menu, MenuAction};
+pub use section::{MenuItem, MenuSection};
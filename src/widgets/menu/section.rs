@@ -0,0 +1,86 @@
+// notcurses::widgets::menu::section
+//
+//!
+//
+
+use crate::input::{Input, KeyMod, Received};
+
+/// A [`Menu`][super::Menu] section, containing a list of [`MenuItem`]s.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MenuSection {
+    /// The name of the section.
+    ///
+    /// An empty name makes this section a separator, and all the sections
+    /// after it will be aligned to the right.
+    pub name: String,
+
+    /// The items of the section. There must be at least one.
+    pub items: Vec<MenuItem>,
+
+    /// The shortcut that unrolls the section.
+    ///
+    /// It will be underlined if its `char` is present in the name.
+    pub shortcut: Input,
+}
+
+/// An item of a [`MenuSection`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MenuItem {
+    /// The label of the item.
+    ///
+    /// An empty label makes this item a horizontal separator.
+    pub label: String,
+
+    /// The shortcut that selects the item, all of them should be distinct.
+    ///
+    /// Use an input with [`Received::NoInput`] for no shortcut.
+    pub shortcut: Input,
+}
+
+/// # constructors
+impl MenuSection {
+    /// Returns a new `MenuSection`.
+    pub fn new(name: &str, items: Vec<MenuItem>, shortcut: Input) -> Self {
+        Self {
+            name: name.into(),
+            items,
+            shortcut,
+        }
+    }
+
+    /// Returns a new separator `MenuSection`.
+    pub fn separator() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if this section is a separator.
+    pub fn is_separator(&self) -> bool {
+        self.name.is_empty()
+    }
+}
+
+/// # constructors
+impl MenuItem {
+    /// Returns a new `MenuItem`.
+    pub fn new(label: &str, shortcut: Input) -> Self {
+        Self {
+            label: label.into(),
+            shortcut,
+        }
+    }
+
+    /// Returns a new `MenuItem` without a shortcut.
+    pub fn without_shortcut(label: &str) -> Self {
+        Self::new(label, Input::new(Received::NoInput, KeyMod::None))
+    }
+
+    /// Returns a new separator `MenuItem`.
+    pub fn separator() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if this item is a separator.
+    pub fn is_separator(&self) -> bool {
+        self.label.is_empty()
+    }
+}
@@ -0,0 +1,31 @@
+// notcurses::widgets
+//
+//! Widgets are higher level drawing objects, built on top of [`Plane`]s.
+//!
+//! Each widget owns the planes it draws on, which are destroyed together
+//! with the widget.
+//!
+//! [`Plane`]: crate::Plane
+//
+
+#[cfg(unix)]
+mod fd_plane;
+mod line_editor;
+mod menu;
+mod plot;
+mod progress_bar;
+mod reel;
+mod selector;
+mod tabbed;
+
+#[cfg(unix)]
+pub use fd_plane::{FdPlane, FdPlaneBuilder, Subprocess, SubprocessBuilder};
+pub use line_editor::{Completer, LineEditor, LineEditorAction, LineEditorBuilder};
+pub use menu::{Menu, MenuAction, MenuBuilder, MenuItem, MenuSection};
+pub use plot::{Plot, PlotBuilder, PlotDomain, PlotSample};
+pub use progress_bar::{ProgressBar, ProgressBarBuilder, ProgressDirection};
+pub use reel::{Reel, ReelBuilder, Tablet, TabletId};
+pub use selector::{
+    MultiSelector, MultiSelectorBuilder, Selector, SelectorAction, SelectorBuilder, SelectorItem,
+};
+pub use tabbed::{TabCallback, TabId, Tabbed, TabbedBuilder};
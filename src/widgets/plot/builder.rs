@@ -0,0 +1,215 @@
+// notcurses::widgets::plot::builder
+//
+//!
+//
+
+use crate::{
+    color::{Channel, Channels},
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::{Plane, Style},
+    sys::{c_api::NcBlitter_u32, widgets::NcPlotOptions, NcPlane},
+    visual::Blitter,
+    widgets::{Plot, PlotDomain, PlotSample},
+    Position, Size,
+};
+use std::ffi::CString;
+
+/// A [`Plot`] builder.
+#[derive(Clone, Debug)]
+pub struct PlotBuilder<T: PlotSample> {
+    position: Position,
+    size: Option<Size>,
+    domain: PlotDomain<T>,
+    max_channel: Channel,
+    min_channel: Channel,
+    blitter: Blitter,
+    rangex: u32,
+    labels: bool,
+    title: String,
+    legend_style: Style,
+    exponential: bool,
+}
+
+mod core_impls {
+    use super::{PlotBuilder, PlotSample};
+
+    impl<T: PlotSample> Default for PlotBuilder<T> {
+        fn default() -> Self {
+            Self {
+                position: Default::default(),
+                size: None,
+                domain: Default::default(),
+                max_channel: Default::default(),
+                min_channel: Default::default(),
+                blitter: Default::default(),
+                rangex: 0,
+                labels: false,
+                title: String::new(),
+                legend_style: Default::default(),
+                exponential: false,
+            }
+        }
+    }
+}
+
+/// # constructors
+impl<T: PlotSample> PlotBuilder<T> {
+    /// Returns a new default `PlotBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `Plot` drawn on a new child plane of the provided `parent`.
+    ///
+    /// # Errors
+    /// - if the domain is fixed and its minimum is not lower than its maximum.
+    /// - if the title contains a NUL character.
+    /// - if notcurses fails to create the plot, e.g. because of an
+    ///   unsupported combination of blitter and exponential y axis.
+    pub fn build(self, parent: &mut Plane) -> Result<Plot<T>> {
+        let (mut flags, min, max) = match self.domain {
+            PlotDomain::Autoscale => (0, T::default(), T::default()),
+            PlotDomain::AutoscaleMax(min) => (NcPlotOptions::DETECTMAXONLY, min, min),
+            PlotDomain::Fixed { min, max } => {
+                if min >= max {
+                    return Error::msg(&format![
+                        "The minimum of the domain ({min:?}) must be lower than the maximum ({max:?})."
+                    ]);
+                }
+                (0, min, max)
+            }
+        };
+        if self.labels {
+            flags |= NcPlotOptions::LABELTICKSD;
+        }
+        if self.exponential {
+            flags |= NcPlotOptions::EXPONENTIALD;
+        }
+
+        let title = CString::new(self.title)
+            .or_else(|_| Error::msg("The title of a `Plot` can't contain NUL characters."))?;
+
+        let default_bg = Channel::with_default();
+        let options = NcPlotOptions {
+            maxchannels: Channels::combine(self.max_channel, default_bg).nc.into(),
+            minchannels: Channels::combine(self.min_channel, default_bg).nc.into(),
+            legendstyle: self.legend_style.into(),
+            gridtype: NcBlitter_u32::from(self.blitter),
+            rangex: self.rangex as _,
+            title: title.as_ptr(),
+            flags: flags as u64,
+        };
+
+        let size = self.size.unwrap_or_else(|| {
+            let (width, height): (i32, i32) = parent.size().into();
+            Size::new(
+                (width - self.position.x()).max(1),
+                (height - self.position.y()).max(1),
+            )
+        });
+        let mut plane = Plane::builder()
+            .position(self.position)
+            .size(size)
+            .build_child(parent)?;
+
+        // the plot takes ownership of the plane, even if it fails to be created.
+        let ncplane: *mut NcPlane = plane.into_ref_mut();
+        core::mem::forget(plane);
+        let ncplot = unsafe { T::create(ncplane, &options, min, max) };
+
+        if ncplot.is_null() {
+            Error::msg("Couldn't create the `Plot`.")
+        } else {
+            Ok(Plot { nc: ncplot })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl<T: PlotSample> PlotBuilder<T> {
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *`(0, 0)`*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the size of the plot, in cells.
+    ///
+    /// Default: *the rest of the parent plane, from the position*.
+    pub fn size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the domain of the dependent (y) axis.
+    ///
+    /// Default: *[`PlotDomain::Autoscale`]*.
+    pub fn domain(mut self, domain: PlotDomain<T>) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Sets the colors of the maximum and minimum values of the domain.
+    ///
+    /// The color of the values in between is interpolated.
+    ///
+    /// Default: *`Channel::default()`* for both.
+    pub fn gradient(mut self, max: impl Into<Channel>, min: impl Into<Channel>) -> Self {
+        self.max_channel = max.into();
+        self.min_channel = min.into();
+        self
+    }
+
+    /// Sets the blitter, which determines the number of "pixels" per cell.
+    ///
+    /// Default: *[`Blitter::Default`]*.
+    pub fn blitter(mut self, blitter: Blitter) -> Self {
+        self.blitter = blitter;
+        self
+    }
+
+    /// Sets the number of samples of the independent (x) axis window.
+    ///
+    /// If it's `0`, it's set to the number of columns times the blitter's
+    /// horizontal resolution.
+    ///
+    /// Default: *`0`*.
+    pub fn rangex(mut self, rangex: u32) -> Self {
+        self.rangex = rangex;
+        self
+    }
+
+    /// Shows labels with the values of the dependent (y) axis.
+    ///
+    /// Default: *`false`*.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets a title, shown along with the labels.
+    ///
+    /// Default: *`""`*.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the style of the labels and title.
+    ///
+    /// Default: *[`Style::None`]*.
+    pub fn legend_style(mut self, style: Style) -> Self {
+        self.legend_style = style;
+        self
+    }
+
+    /// Uses an exponential dependent (y) axis.
+    ///
+    /// Default: *`false`*.
+    pub fn exponential(mut self, exponential: bool) -> Self {
+        self.exponential = exponential;
+        self
+    }
+}
@@ -0,0 +1,37 @@
+// notcurses::widgets::plot::domain
+//
+//!
+//
+
+use crate::widgets::PlotSample;
+
+/// The range of values of the dependent (y) axis of a [`Plot`][super::Plot].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PlotDomain<T: PlotSample> {
+    /// Both the minimum and the maximum are detected from the samples.
+    ///
+    /// This is the default domain.
+    #[default]
+    Autoscale,
+
+    /// The minimum is fixed, and only the maximum is detected from the samples.
+    AutoscaleMax(T),
+
+    /// Both the minimum and the maximum are fixed.
+    ///
+    /// The minimum must be lower than the maximum.
+    Fixed {
+        /// The minimum value.
+        min: T,
+        /// The maximum value.
+        max: T,
+    },
+}
+
+/// # methods
+impl<T: PlotSample> PlotDomain<T> {
+    /// Returns `true` if the maximum is detected from the samples.
+    pub fn is_autoscaling(&self) -> bool {
+        !matches!(self, Self::Fixed { .. })
+    }
+}
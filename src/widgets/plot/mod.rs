@@ -0,0 +1,14 @@
+// notcurses::widgets::plot
+//
+//!
+//
+
+mod builder;
+mod domain;
+mod plot;
+mod sample;
+
+pub use builder::PlotBuilder;
+pub use domain::PlotDomain;
+pub use plot::Plot;
+pub use sample::PlotSample;
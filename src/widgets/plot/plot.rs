@@ -0,0 +1,118 @@
+// notcurses::widgets::plot::plot
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    sys::c_api::NCRESULT_ERR,
+    widgets::{PlotBuilder, PlotSample},
+};
+
+/// A histogram of `u64` or `f64` samples.
+///
+/// The independent (x) axis is a sliding window of [`rangex`] consecutive
+/// samples, and the dependent (y) axis covers its [`PlotDomain`].
+///
+/// It's drawn on its own plane, which is destroyed together with the plot.
+///
+/// [`rangex`]: PlotBuilder#method.rangex
+/// [`PlotDomain`]: crate::PlotDomain
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// let mut stdplane = Plane::new(&mut nc)?;
+/// let mut plot = Plot::<u64>::builder()
+///     .size((40, 8))
+///     .gradient(Rgb::new(255, 0, 0), Rgb::new(0, 0, 255))
+///     .blitter(Blitter::Braille)
+///     .build(&mut stdplane)?;
+/// for x in 0..100 {
+///     plot.add_sample(x, x % 17)?;
+/// }
+/// assert_eq![99 % 17, plot.sample(99)?];
+/// stdplane.render()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Plot<T: PlotSample> {
+    pub(super) nc: *mut T::NcPlot,
+}
+
+mod core_impls {
+    use super::{Plot, PlotSample};
+    use core::fmt;
+
+    impl<T: PlotSample> Drop for Plot<T> {
+        fn drop(&mut self) {
+            if crate::Notcurses::is_initialized() {
+                unsafe { T::destroy(self.nc) };
+            }
+        }
+    }
+
+    impl<T: PlotSample> fmt::Debug for Plot<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "Plot<{}> {{ {:?} }}",
+                core::any::type_name::<T>(),
+                self.nc
+            )
+        }
+    }
+}
+
+/// # constructors
+impl<T: PlotSample> Plot<T> {
+    /// Returns a new [`PlotBuilder`].
+    pub fn builder() -> PlotBuilder<T> {
+        PlotBuilder::new()
+    }
+}
+
+/// # methods
+impl<T: PlotSample> Plot<T> {
+    /// Adds `y` to the current value of the sample at `x`, and redraws the plot.
+    ///
+    /// If `x` is beyond the current window, the window slides forward to
+    /// include it, and the samples left behind are lost.
+    ///
+    /// # Errors
+    /// - if `x` is behind the current window.
+    pub fn add_sample(&mut self, x: u64, y: T) -> Result<()> {
+        match unsafe { T::add_sample(self.nc, x, y) } {
+            NCRESULT_ERR => Error::msg(&format!["Couldn't add the sample at x={x}."]),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sets `y` as the value of the sample at `x`, and redraws the plot.
+    ///
+    /// If `x` is beyond the current window, the window slides forward to
+    /// include it, and the samples left behind are lost.
+    ///
+    /// # Errors
+    /// - if `x` is behind the current window.
+    pub fn set_sample(&mut self, x: u64, y: T) -> Result<()> {
+        match unsafe { T::set_sample(self.nc, x, y) } {
+            NCRESULT_ERR => Error::msg(&format!["Couldn't set the sample at x={x}."]),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the value of the sample at `x`.
+    ///
+    /// # Errors
+    /// - if `x` is outside the current window.
+    pub fn sample(&self, x: u64) -> Result<T> {
+        let mut y = T::default();
+        match unsafe { T::sample(self.nc, x, &mut y) } {
+            NCRESULT_ERR => Error::msg(&format!["There's no sample at x={x}."]),
+            _ => Ok(y),
+        }
+    }
+}
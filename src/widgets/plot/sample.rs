@@ -0,0 +1,89 @@
+// notcurses::widgets::plot::sample
+//
+//!
+//
+
+use crate::sys::{
+    c_api,
+    widgets::{NcPlotF64, NcPlotOptions, NcPlotU64},
+    NcPlane,
+};
+use core::ffi::c_int;
+
+/// A type of sample that can be plotted by a [`Plot`][super::Plot].
+///
+/// It's implemented for `u64` and `f64`.
+pub trait PlotSample: sealed::RawPlot + Copy + Default + PartialOrd + core::fmt::Debug {}
+
+impl PlotSample for u64 {}
+impl PlotSample for f64 {}
+
+pub(crate) mod sealed {
+    use super::*;
+
+    // The functions of the underlying C plot, for each type of sample.
+    pub trait RawPlot: Sized {
+        type NcPlot;
+
+        unsafe fn create(
+            plane: *mut NcPlane,
+            options: &NcPlotOptions,
+            min: Self,
+            max: Self,
+        ) -> *mut Self::NcPlot;
+        unsafe fn destroy(plot: *mut Self::NcPlot);
+        unsafe fn add_sample(plot: *mut Self::NcPlot, x: u64, y: Self) -> c_int;
+        unsafe fn set_sample(plot: *mut Self::NcPlot, x: u64, y: Self) -> c_int;
+        unsafe fn sample(plot: *const Self::NcPlot, x: u64, y: *mut Self) -> c_int;
+    }
+
+    impl RawPlot for u64 {
+        type NcPlot = NcPlotU64;
+
+        unsafe fn create(
+            plane: *mut NcPlane,
+            options: &NcPlotOptions,
+            min: u64,
+            max: u64,
+        ) -> *mut NcPlotU64 {
+            c_api::ncuplot_create(plane, options, min, max)
+        }
+        unsafe fn destroy(plot: *mut NcPlotU64) {
+            c_api::ncuplot_destroy(plot)
+        }
+        unsafe fn add_sample(plot: *mut NcPlotU64, x: u64, y: u64) -> c_int {
+            c_api::ncuplot_add_sample(plot, x, y)
+        }
+        unsafe fn set_sample(plot: *mut NcPlotU64, x: u64, y: u64) -> c_int {
+            c_api::ncuplot_set_sample(plot, x, y)
+        }
+        unsafe fn sample(plot: *const NcPlotU64, x: u64, y: *mut u64) -> c_int {
+            c_api::ncuplot_sample(plot, x, y)
+        }
+    }
+
+    impl RawPlot for f64 {
+        type NcPlot = NcPlotF64;
+
+        unsafe fn create(
+            plane: *mut NcPlane,
+            options: &NcPlotOptions,
+            min: f64,
+            max: f64,
+        ) -> *mut NcPlotF64 {
+            c_api::ncdplot_create(plane, options, min, max)
+        }
+        unsafe fn destroy(plot: *mut NcPlotF64) {
+            c_api::ncdplot_destroy(plot)
+        }
+        unsafe fn add_sample(plot: *mut NcPlotF64, x: u64, y: f64) -> c_int {
+            c_api::ncdplot_add_sample(plot, x, y)
+        }
+        unsafe fn set_sample(plot: *mut NcPlotF64, x: u64, y: f64) -> c_int {
+            c_api::ncdplot_set_sample(plot, x, y)
+        }
+        unsafe fn sample(plot: *const NcPlotF64, x: u64, y: *mut f64) -> c_int {
+            c_api::ncdplot_sample(plot, x, y)
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// notcurses::widgets::progress_bar::builder
+//
+//!
+//
+
+use crate::{
+    color::Channel,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{c_api, widgets::NcProgBarOptions, NcPlane},
+    widgets::progress_bar::{ProgressBar, ProgressDirection},
+    Position,
+};
+
+/// A [`ProgressBar`] builder.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProgressBarBuilder {
+    direction: ProgressDirection,
+    position: Option<Position>,
+    length: Option<u32>,
+    thickness: Option<u32>,
+    // upper-left, upper-right, bottom-left, bottom-right
+    corners: [Channel; 4],
+}
+
+/// # constructors
+impl ProgressBarBuilder {
+    /// Returns a new default `ProgressBarBuilder`.
+    ///
+    /// The bar will progress from left to right, along a whole row of the
+    /// parent plane starting at its cursor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `ProgressBar` drawn on a new child plane of the provided `parent`.
+    ///
+    /// # Errors
+    /// - if the length is not greater than the thickness, since the
+    ///   orientation depends on the shape of the plane.
+    pub fn build(self, parent: &mut Plane) -> Result<ProgressBar> {
+        let horizontal = self.direction.is_horizontal();
+        let (parent_w, parent_h) = parent.size().into();
+
+        let position = self
+            .position
+            .unwrap_or_else(|| Position::new(0, parent.cursor().y()));
+        let length = self
+            .length
+            .unwrap_or(if horizontal { parent_w } else { parent_h });
+        let thickness = self.thickness.unwrap_or(1);
+        if length <= thickness {
+            return Error::msg("The length of a `ProgressBar` must be greater than its thickness.");
+        }
+
+        let size = if horizontal {
+            (length, thickness)
+        } else {
+            (thickness, length)
+        };
+        let mut plane = Plane::builder()
+            .position(position)
+            .size(size)
+            .build_child(parent)?;
+
+        let [ul, ur, bl, br] = self.corners;
+        let options = NcProgBarOptions {
+            ulchannel: ul.nc.into(),
+            urchannel: ur.nc.into(),
+            blchannel: bl.nc.into(),
+            brchannel: br.nc.into(),
+            flags: if self.direction.is_retrograde() {
+                NcProgBarOptions::RETROGRADE as u64
+            } else {
+                0
+            },
+        };
+
+        // the progress bar takes ownership of the plane, even if it fails to be created.
+        let ncplane: *mut NcPlane = plane.into_ref_mut();
+        core::mem::forget(plane);
+        let ncprogbar = unsafe { c_api::ncprogbar_create(ncplane, &options) };
+
+        if ncprogbar.is_null() {
+            Error::msg("Couldn't create the `ProgressBar`.")
+        } else {
+            Ok(ProgressBar { nc: ncprogbar })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl ProgressBarBuilder {
+    /// Sets the direction of progress, which determines the orientation.
+    ///
+    /// Default: *[`ProgressDirection::LeftToRight`]*.
+    pub fn direction(mut self, direction: ProgressDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *the first column of the parent's cursor row*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// Sets the length of the bar, along the direction of progress.
+    ///
+    /// Default: *the width (or height, if vertical) of the parent plane*.
+    pub fn length(mut self, length: u32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the thickness of the bar, perpendicular to the direction of progress.
+    ///
+    /// Default: *`1`*.
+    pub fn thickness(mut self, thickness: u32) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// Sets a single color for the whole bar.
+    ///
+    /// Default: *`Channel::default()`*.
+    pub fn color(mut self, channel: impl Into<Channel>) -> Self {
+        self.corners = [channel.into(); 4];
+        self
+    }
+
+    /// Sets a gradient between the four corners of the bar.
+    ///
+    /// "Up" is the direction the bar progresses towards, and "bottom" is
+    /// the direction of origin, so the gradient follows the orientation.
+    ///
+    /// Default: *`Channel::default()`* for all of them.
+    pub fn gradient(
+        mut self,
+        upper_left: impl Into<Channel>,
+        upper_right: impl Into<Channel>,
+        bottom_left: impl Into<Channel>,
+        bottom_right: impl Into<Channel>,
+    ) -> Self {
+        self.corners = [
+            upper_left.into(),
+            upper_right.into(),
+            bottom_left.into(),
+            bottom_right.into(),
+        ];
+        self
+    }
+}
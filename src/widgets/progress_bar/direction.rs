@@ -0,0 +1,43 @@
+// notcurses::widgets::progress_bar::direction
+//
+//!
+//
+
+/// The direction in which a [`ProgressBar`][super::ProgressBar] progresses.
+///
+/// It determines both the orientation and whether the bar is *retrograde*.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProgressDirection {
+    /// Horizontal, from left to right.
+    ///
+    /// This is the default direction.
+    #[default]
+    LeftToRight,
+
+    /// Horizontal and retrograde, from right to left.
+    RightToLeft,
+
+    /// Vertical, from bottom to top.
+    BottomToTop,
+
+    /// Vertical and retrograde, from top to bottom.
+    TopToBottom,
+}
+
+/// # methods
+impl ProgressDirection {
+    /// Returns `true` if it's an horizontal direction.
+    pub const fn is_horizontal(&self) -> bool {
+        matches!(self, Self::LeftToRight | Self::RightToLeft)
+    }
+
+    /// Returns `true` if it's a vertical direction.
+    pub const fn is_vertical(&self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// Returns `true` if it's a *retrograde* direction.
+    pub const fn is_retrograde(&self) -> bool {
+        matches!(self, Self::RightToLeft | Self::TopToBottom)
+    }
+}
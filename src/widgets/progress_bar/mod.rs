@@ -0,0 +1,12 @@
+// notcurses::widgets::progress_bar
+//
+//!
+//
+
+mod builder;
+mod direction;
+mod progress_bar;
+
+pub use builder::ProgressBarBuilder;
+pub use direction::ProgressDirection;
+pub use progress_bar::ProgressBar;
@@ -0,0 +1,102 @@
+// notcurses::widgets::progress_bar::progress_bar
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    sys::widgets::NcProgBar,
+    widgets::progress_bar::ProgressBarBuilder,
+};
+
+/// A progress bar.
+///
+/// It's drawn on its own plane, which is destroyed together with the bar.
+///
+/// It's horizontal or vertical depending on its [`ProgressDirection`],
+/// and it's initially at 0%.
+///
+/// [`ProgressDirection`]: super::ProgressDirection
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// let mut cli = nc.cli_plane()?;
+/// let mut bar = ProgressBar::builder()
+///     .gradient(Rgb::new(0, 40, 0), Rgb::new(0, 255, 0), Rgb::new(0, 40, 0), Rgb::new(0, 255, 0))
+///     .build(&mut cli)?;
+/// for n in 0..=10 {
+///     bar.set_progress(n as f64 / 10.)?;
+///     cli.render()?;
+/// }
+/// assert_eq![1.0, bar.progress()];
+/// # Ok(())
+/// # }
+/// ```
+pub struct ProgressBar {
+    pub(super) nc: *mut NcProgBar,
+}
+
+mod core_impls {
+    use super::ProgressBar;
+    use core::fmt;
+
+    impl Drop for ProgressBar {
+        fn drop(&mut self) {
+            if crate::Notcurses::is_initialized() {
+                self.into_ref_mut().destroy();
+            }
+        }
+    }
+
+    impl fmt::Debug for ProgressBar {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "ProgressBar {{ progress: {} }}", self.progress())
+        }
+    }
+}
+
+/// # constructors
+impl ProgressBar {
+    /// Returns a new [`ProgressBarBuilder`].
+    pub fn builder() -> ProgressBarBuilder {
+        ProgressBarBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcProgBar`].
+    pub fn into_ref(&self) -> &NcProgBar {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcProgBar`].
+    pub fn into_ref_mut(&mut self) -> &mut NcProgBar {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl ProgressBar {
+    /// Returns the current progress, between `0.0` and `1.0`.
+    pub fn progress(&self) -> f64 {
+        self.into_ref().progress()
+    }
+
+    /// Sets the `progress`, which must be between `0.0` and `1.0`, inclusive.
+    ///
+    /// The bar is redrawn on its plane, and it will be shown after rendering.
+    ///
+    /// # Errors
+    /// - if `progress` is out of range, or is not a number.
+    pub fn set_progress(&mut self, progress: f64) -> Result<()> {
+        if !(0.0..=1.0).contains(&progress) {
+            return Error::msg(&format![
+                "The progress must be between 0.0 and 1.0, but it's {progress}."
+            ]);
+        }
+        Ok(self.into_ref_mut().set_progress(progress)?)
+    }
+}
@@ -0,0 +1,168 @@
+// notcurses::widgets::reel::builder
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{c_api, widgets::NcReelOptions, NcPlane},
+    widgets::reel::Reel,
+    Position, Size,
+};
+
+/// A [`Reel`] builder.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReelBuilder {
+    position: Position,
+    size: Option<Size>,
+    border_mask: u32,
+    border_channels: Channels,
+    tablet_mask: u32,
+    tablet_channels: Channels,
+    focused_channels: Channels,
+    infinite_scroll: bool,
+    circular: bool,
+}
+
+/// # constructors
+impl ReelBuilder {
+    /// Returns a new default `ReelBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new empty `Reel` drawn on a new child plane of the provided `parent`.
+    ///
+    /// # Errors
+    /// - if it's circular without infinite scroll.
+    /// - if notcurses fails to create the reel.
+    pub fn build(self, parent: &mut Plane) -> Result<Reel> {
+        if self.circular && !self.infinite_scroll {
+            return Error::msg("A circular `Reel` needs infinite scroll.");
+        }
+        let mut flags = 0;
+        if self.infinite_scroll {
+            flags |= NcReelOptions::INFINITESCROLL;
+        }
+        if self.circular {
+            flags |= NcReelOptions::CIRCULAR;
+        }
+        let options = NcReelOptions {
+            bordermask: self.border_mask,
+            borderchan: self.border_channels.nc.into(),
+            tabletmask: self.tablet_mask,
+            tabletchan: self.tablet_channels.nc.into(),
+            focusedchan: self.focused_channels.nc.into(),
+            flags: flags as u64,
+        };
+
+        let size = self.size.unwrap_or_else(|| {
+            let (width, height): (i32, i32) = parent.size().into();
+            Size::new(
+                (width - self.position.x()).max(1),
+                (height - self.position.y()).max(1),
+            )
+        });
+        let mut plane = Plane::builder()
+            .position(self.position)
+            .size(size)
+            .build_child(parent)?;
+
+        // the reel takes ownership of the plane, even if it fails to be created.
+        let ncplane: *mut NcPlane = plane.into_ref_mut();
+        core::mem::forget(plane);
+        let ncreel = unsafe { c_api::ncreel_create(ncplane, &options) };
+
+        if ncreel.is_null() {
+            Error::msg("Couldn't create the `Reel`.")
+        } else {
+            Ok(Reel {
+                nc: ncreel,
+                tablets: Vec::new(),
+                next_id: 0,
+            })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl ReelBuilder {
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *`(0, 0)`*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the size of the reel, including its border.
+    ///
+    /// Default: *the rest of the parent plane, from the position*.
+    pub fn size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the channels of the reel border.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn border(mut self, channels: impl Into<Channels>) -> Self {
+        self.border_channels = channels.into();
+        self
+    }
+
+    /// Sets which sides of the reel border are *not* drawn.
+    ///
+    /// It's a bitmask with `1` for top, `2` for right, `4` for bottom and `8` for left.
+    ///
+    /// Default: *`0`*.
+    pub fn border_mask(mut self, mask: u32) -> Self {
+        self.border_mask = mask;
+        self
+    }
+
+    /// Sets the channels of the tablet borders.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn tablet_border(mut self, channels: impl Into<Channels>) -> Self {
+        self.tablet_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the focused tablet border.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn focused_border(mut self, channels: impl Into<Channels>) -> Self {
+        self.focused_channels = channels.into();
+        self
+    }
+
+    /// Sets which sides of the tablet borders are *not* drawn.
+    ///
+    /// It's a bitmask with `1` for top, `2` for right, `4` for bottom and `8` for left.
+    ///
+    /// Default: *`0`*.
+    pub fn tablet_mask(mut self, mask: u32) -> Self {
+        self.tablet_mask = mask;
+        self
+    }
+
+    /// Allows the focused tablet to move freely, instead of stopping at the edges.
+    ///
+    /// Default: *`false`*.
+    pub fn infinite_scroll(mut self, infinite_scroll: bool) -> Self {
+        self.infinite_scroll = infinite_scroll;
+        self
+    }
+
+    /// Makes the navigation wrap around from the last tablet to the first,
+    /// and vice versa. It requires infinite scroll.
+    ///
+    /// Default: *`false`*.
+    pub fn circular(mut self, circular: bool) -> Self {
+        self.circular = circular;
+        self
+    }
+}
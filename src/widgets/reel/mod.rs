@@ -0,0 +1,12 @@
+// notcurses::widgets::reel
+//
+//!
+//
+
+mod builder;
+mod reel;
+mod tablet;
+
+pub use builder::ReelBuilder;
+pub use reel::Reel;
+pub use tablet::{Tablet, TabletId};
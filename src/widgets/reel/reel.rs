@@ -0,0 +1,222 @@
+// notcurses::widgets::reel::reel
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::Input,
+    sys::{
+        c_api::{self, libc::c_void, NCRESULT_ERR},
+        widgets::{NcReel, NcTablet},
+    },
+    widgets::reel::{tablet::trampoline, ReelBuilder, Tablet, TabletId},
+};
+use core::ptr::null_mut;
+
+/// A scrollable list of [`Tablet`]s, of which one is focused.
+///
+/// As much of the focused tablet as possible is always shown, and the
+/// remaining space is used by its neighbours.
+///
+/// Each tablet is drawn on its own plane, which is owned by the reel.
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// # struct LogEntry(Vec<String>);
+/// # impl Tablet for LogEntry {
+/// #     fn draw(&mut self, _: &mut Plane, _: bool) -> NotcursesResult<u32> { Ok(1) }
+/// # }
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// # nc.inject_inputs([Key::Down, Key::Esc]);
+/// let mut stdplane = Plane::new(&mut nc)?;
+/// let mut reel = Reel::builder().infinite_scroll(true).build(&mut stdplane)?;
+/// let first = reel.add(LogEntry(vec!["first entry".into()]))?;
+/// reel.add(LogEntry(vec!["second entry".into()]))?;
+/// assert_eq![Some(first), reel.focused()];
+/// loop {
+///     stdplane.render()?;
+///     let input = nc.get_event()?;
+///     if input.is_key(Key::Esc) {
+///         break;
+///     }
+///     reel.offer_input(&input);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Reel {
+    pub(super) nc: *mut NcReel,
+    // the tablets in order of addition, each boxed twice to have a thin userptr.
+    pub(super) tablets: Vec<(TabletId, *mut NcTablet, *mut Box<dyn Tablet>)>,
+    pub(super) next_id: u64,
+}
+
+mod core_impls {
+    use super::Reel;
+    use core::fmt;
+
+    impl Drop for Reel {
+        fn drop(&mut self) {
+            if crate::Notcurses::is_initialized() {
+                unsafe { crate::sys::c_api::ncreel_destroy(self.nc) };
+            }
+            for (_, _, tablet) in self.tablets.drain(..) {
+                drop(unsafe { Box::from_raw(tablet) });
+            }
+        }
+    }
+
+    impl fmt::Debug for Reel {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "Reel {{ tablets: {}, focused: {:?} }}",
+                self.len(),
+                self.focused()
+            )
+        }
+    }
+}
+
+/// # constructors
+impl Reel {
+    /// Returns a new [`ReelBuilder`].
+    pub fn builder() -> ReelBuilder {
+        ReelBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcReel`].
+    pub fn into_ref(&self) -> &NcReel {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcReel`].
+    pub fn into_ref_mut(&mut self) -> &mut NcReel {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl Reel {
+    /// Returns the number of tablets.
+    pub fn len(&self) -> usize {
+        self.tablets.len()
+    }
+
+    /// Returns `true` if there are no tablets.
+    pub fn is_empty(&self) -> bool {
+        self.tablets.is_empty()
+    }
+
+    /// Adds a new `tablet` after the focused one, and redraws the reel.
+    ///
+    /// The first tablet added gets the focus.
+    pub fn add(&mut self, tablet: impl Tablet + 'static) -> Result<TabletId> {
+        let boxed: Box<Box<dyn Tablet>> = Box::new(Box::new(tablet));
+        let userptr = Box::into_raw(boxed);
+
+        let nctablet = unsafe {
+            c_api::ncreel_add(
+                self.nc,
+                null_mut(),
+                null_mut(),
+                Some(trampoline),
+                userptr as *mut c_void,
+            )
+        };
+        if nctablet.is_null() {
+            drop(unsafe { Box::from_raw(userptr) });
+            return Error::msg("Couldn't add the tablet to the `Reel`.");
+        }
+
+        let id = TabletId(self.next_id);
+        self.next_id += 1;
+        self.tablets.push((id, nctablet, userptr));
+        Ok(id)
+    }
+
+    /// Deletes the tablet with the provided `id`, returning it, and redraws the reel.
+    ///
+    /// Returns `None` if there's no such tablet.
+    pub fn del(&mut self, id: TabletId) -> Option<Box<dyn Tablet>> {
+        let index = self.tablets.iter().position(|(i, _, _)| *i == id)?;
+        let (_, nctablet, userptr) = self.tablets.remove(index);
+        unsafe { c_api::ncreel_del(self.nc, nctablet) };
+        Some(*unsafe { Box::from_raw(userptr) })
+    }
+
+    /// Returns the identifier of the focused tablet, if there are any.
+    pub fn focused(&self) -> Option<TabletId> {
+        let nctablet = unsafe { c_api::ncreel_focused(self.nc) };
+        self.find_id(nctablet)
+    }
+
+    /// Moves the focus to the next tablet, and returns its identifier.
+    ///
+    /// The reel is redrawn.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<TabletId> {
+        let nctablet = unsafe { c_api::ncreel_next(self.nc) };
+        self.find_id(nctablet)
+    }
+
+    /// Moves the focus to the previous tablet, and returns its identifier.
+    ///
+    /// The reel is redrawn.
+    pub fn prev(&mut self) -> Option<TabletId> {
+        let nctablet = unsafe { c_api::ncreel_prev(self.nc) };
+        self.find_id(nctablet)
+    }
+
+    /// Returns a shared reference to the tablet with the provided `id`.
+    pub fn tablet(&self, id: TabletId) -> Option<&dyn Tablet> {
+        let (_, _, userptr) = self.tablets.iter().find(|(i, _, _)| *i == id)?;
+        Some(unsafe { &**(*userptr) })
+    }
+
+    /// Returns an exclusive reference to the tablet with the provided `id`.
+    ///
+    /// Call [`redraw`][Reel#method.redraw] afterwards to show any changes.
+    pub fn tablet_mut(&mut self, id: TabletId) -> Option<&mut dyn Tablet> {
+        let (_, _, userptr) = self.tablets.iter().find(|(i, _, _)| *i == id)?;
+        Some(unsafe { &mut **(*userptr) })
+    }
+
+    /// Returns the identifiers of all the tablets, in order of addition.
+    pub fn tablet_ids(&self) -> impl Iterator<Item = TabletId> + '_ {
+        self.tablets.iter().map(|(id, _, _)| *id)
+    }
+
+    /// Redraws the whole reel, calling [`Tablet::draw`] for each visible tablet.
+    ///
+    /// # Errors
+    /// - if any visible tablet fails to draw itself.
+    pub fn redraw(&mut self) -> Result<()> {
+        match unsafe { c_api::ncreel_redraw(self.nc) } {
+            NCRESULT_ERR => Error::msg("Couldn't redraw the `Reel`."),
+            _ => Ok(()),
+        }
+    }
+
+    /// Offers an `input` to the reel.
+    ///
+    /// Returns `true` if it was consumed, which happens with the up, down,
+    /// page up and page down keys, the mouse wheel, and clicks over a tablet.
+    pub fn offer_input(&mut self, input: &Input) -> bool {
+        unsafe { c_api::ncreel_offer_input(self.nc, &input.into()) }
+    }
+}
+
+impl Reel {
+    fn find_id(&self, nctablet: *mut NcTablet) -> Option<TabletId> {
+        self.tablets
+            .iter()
+            .find(|(_, nc, _)| *nc == nctablet)
+            .map(|(id, _, _)| *id)
+    }
+}
@@ -0,0 +1,74 @@
+// notcurses::widgets::reel::tablet
+//
+//!
+//
+
+use crate::{
+    error::NotcursesResult as Result,
+    plane::Plane,
+    sys::{c_api, widgets::NcTablet},
+};
+use core::{ffi::c_int, mem::ManuallyDrop};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// The contents of a tablet in a [`Reel`][super::Reel].
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// struct LogEntry(Vec<String>);
+///
+/// impl Tablet for LogEntry {
+///     fn draw(&mut self, plane: &mut Plane, clip_top: bool) -> NotcursesResult<u32> {
+///         let rows = (plane.size().h() as usize).min(self.0.len());
+///         let lines = if clip_top {
+///             &self.0[self.0.len() - rows..]
+///         } else {
+///             &self.0[..rows]
+///         };
+///         for (y, line) in lines.iter().enumerate() {
+///             plane.putstr_at((0, y as u32), line)?;
+///         }
+///         Ok(rows as u32)
+///     }
+/// }
+///
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// # let mut stdplane = Plane::new(&mut nc)?;
+/// let mut reel = Reel::builder().build(&mut stdplane)?;
+/// reel.add(LogEntry(vec!["a line".into(), "another line".into()]))?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Tablet {
+    /// Draws the tablet on its `plane`, returning the number of rows used.
+    ///
+    /// The plane is cleared before each call, and it's as tall as the space
+    /// available for the tablet in the reel. When the tablet doesn't fit,
+    /// `clip_top` indicates which side is going to be hidden: if `true` its
+    /// last rows should be drawn, and otherwise its first rows.
+    ///
+    /// The returned number of rows is clamped to the height of the plane.
+    fn draw(&mut self, plane: &mut Plane, clip_top: bool) -> Result<u32>;
+}
+
+/// Identifies a [`Tablet`] in a [`Reel`][super::Reel].
+///
+/// Identifiers are never reused by the same reel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TabletId(pub(super) u64);
+
+// The C callback that forwards to the `Tablet` stored in the userptr.
+pub(super) unsafe extern "C" fn trampoline(nctablet: *mut NcTablet, drawfromtop: bool) -> c_int {
+    let tablet = &mut *(c_api::nctablet_userptr(nctablet) as *mut Box<dyn Tablet>);
+
+    // the plane is owned by the reel, so it must not be destroyed here.
+    let mut plane = ManuallyDrop::new(Plane::from(&mut *c_api::nctablet_plane(nctablet)));
+    let res = catch_unwind(AssertUnwindSafe(|| tablet.draw(&mut plane, !drawfromtop)));
+
+    match res {
+        Ok(Ok(rows)) => rows.min(plane.size().h() as u32) as c_int,
+        _ => c_api::NCRESULT_ERR,
+    }
+}
@@ -0,0 +1,220 @@
+// notcurses::widgets::selector::builder
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::{Plane, Style},
+    sys::{
+        c_api,
+        widgets::{NcSelectorItem, NcSelectorOptions},
+    },
+    widgets::selector::{
+        common::{CStrings, Common},
+        Selector, SelectorItem,
+    },
+    Position,
+};
+
+/// A [`Selector`] builder.
+#[derive(Clone, Debug)]
+pub struct SelectorBuilder<T = ()> {
+    items: Vec<SelectorItem<T>>,
+    default_item: usize,
+    common: Common,
+}
+
+mod core_impls {
+    use super::SelectorBuilder;
+
+    impl<T> Default for SelectorBuilder<T> {
+        fn default() -> Self {
+            Self {
+                items: vec![],
+                default_item: 0,
+                common: Default::default(),
+            }
+        }
+    }
+}
+
+/// # constructors
+impl<T> SelectorBuilder<T> {
+    /// Returns a new default `SelectorBuilder`, without items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `Selector` drawn on a new child plane of the provided `parent`.
+    ///
+    /// # Errors
+    /// - if the default item is out of bounds.
+    /// - if two items have the same option.
+    /// - if any string contains a NUL character.
+    /// - if notcurses fails to create the selector.
+    pub fn build(self, parent: &mut Plane) -> Result<Selector<T>> {
+        if !self.items.is_empty() && self.default_item >= self.items.len() {
+            return Error::msg(&format![
+                "The default item ({}) is out of bounds ({} items).",
+                self.default_item,
+                self.items.len()
+            ]);
+        }
+
+        // the selected item is identified by its option.
+        for (i, item) in self.items.iter().enumerate() {
+            if self.items[..i]
+                .iter()
+                .any(|other| other.option == item.option)
+            {
+                return Error::msg(&format!["Duplicated item option {:?}.", item.option]);
+            }
+        }
+
+        let mut strings = CStrings::default();
+        let mut nc_items = vec![];
+        for item in &self.items {
+            nc_items.push(NcSelectorItem {
+                option: strings.ptr_non_null(&item.option)?,
+                desc: strings.ptr_non_null(&item.desc)?,
+            });
+        }
+        // the list of items is terminated by an item with a null option.
+        nc_items.push(NcSelectorItem::default());
+
+        let c = &self.common;
+        let options = NcSelectorOptions {
+            title: strings.ptr(&c.title)?,
+            secondary: strings.ptr(&c.secondary)?,
+            footer: strings.ptr(&c.footer)?,
+            items: nc_items.as_ptr(),
+            defidx: self.default_item as _,
+            maxdisplay: c.max_display,
+            opchannels: c.option_channels.nc.into(),
+            descchannels: c.desc_channels.nc.into(),
+            titlechannels: c.title_channels.nc.into(),
+            footchannels: c.footer_channels.nc.into(),
+            boxchannels: c.box_channels.nc.into(),
+            flags: 0,
+        };
+
+        // the selector takes ownership of the plane, even if it fails to be created.
+        let ncplane = c.plane(parent)?;
+        let ncselector = unsafe { c_api::ncselector_create(ncplane, &options) };
+
+        if ncselector.is_null() {
+            Error::msg("Couldn't create the `Selector`.")
+        } else {
+            Ok(Selector {
+                nc: ncselector,
+                items: self.items,
+            })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl<T> SelectorBuilder<T> {
+    /// Adds an `item` after the existing ones.
+    pub fn item(mut self, item: impl Into<SelectorItem<T>>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Adds multiple `items` after the existing ones.
+    pub fn items<I: Into<SelectorItem<T>>>(mut self, items: impl IntoIterator<Item = I>) -> Self {
+        self.items.extend(items.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the index of the item initially selected.
+    ///
+    /// Default: *`0`*.
+    pub fn default_item(mut self, index: usize) -> Self {
+        self.default_item = index;
+        self
+    }
+
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *`(0, 0)`*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.common.position = position.into();
+        self
+    }
+
+    /// Sets the title, shown above the items.
+    ///
+    /// Default: *`""`*.
+    pub fn title(mut self, title: &str) -> Self {
+        self.common.title = title.into();
+        self
+    }
+
+    /// Sets the secondary title, shown in the upper border.
+    ///
+    /// Default: *`""`*.
+    pub fn secondary(mut self, secondary: &str) -> Self {
+        self.common.secondary = secondary.into();
+        self
+    }
+
+    /// Sets the footer, shown in the lower border.
+    ///
+    /// Default: *`""`*.
+    pub fn footer(mut self, footer: &str) -> Self {
+        self.common.footer = footer.into();
+        self
+    }
+
+    /// Sets the maximum number of items shown at once, or `0` for no limit.
+    ///
+    /// Default: *`0`*.
+    pub fn max_display(mut self, max_display: u32) -> Self {
+        self.common.max_display = max_display;
+        self
+    }
+
+    /// Sets the channels of the options and of their descriptions.
+    ///
+    /// Default: *`Channels::default()`* for both.
+    pub fn item_channels(mut self, option: impl Into<Channels>, desc: impl Into<Channels>) -> Self {
+        self.common.option_channels = option.into();
+        self.common.desc_channels = desc.into();
+        self
+    }
+
+    /// Sets the channels of the title.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn title_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.common.title_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the secondary title and the footer.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn footer_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.common.footer_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the border.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn box_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.common.box_channels = channels.into();
+        self
+    }
+
+    /// Sets the text style.
+    ///
+    /// Default: *[`Style::None`]*.
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.common.style = style.into();
+        self
+    }
+}
@@ -0,0 +1,74 @@
+// notcurses::widgets::selector::common
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::{Plane, Style},
+    sys::NcPlane,
+    Position,
+};
+use core::{ffi::c_char, ptr::null};
+use std::ffi::CString;
+
+// The settings shared by the `SelectorBuilder` and the `MultiSelectorBuilder`.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Common {
+    pub position: Position,
+    pub title: String,
+    pub secondary: String,
+    pub footer: String,
+    pub max_display: u32,
+    pub option_channels: Channels,
+    pub desc_channels: Channels,
+    pub title_channels: Channels,
+    pub footer_channels: Channels,
+    pub box_channels: Channels,
+    pub style: Style,
+}
+
+impl Common {
+    // Returns a new plane for the widget to take ownership of.
+    //
+    // The widget resizes the plane to fit its contents.
+    pub fn plane(&self, parent: &mut Plane) -> Result<*mut NcPlane> {
+        let mut plane = Plane::builder()
+            .position(self.position)
+            .size((1, 1))
+            .build_child(parent)?;
+        plane.set_styles(self.style);
+
+        let ncplane: *mut NcPlane = plane.into_ref_mut();
+        core::mem::forget(plane);
+        Ok(ncplane)
+    }
+}
+
+// Keeps alive the C strings until the widget has been created, since
+// notcurses makes its own copy of them.
+#[derive(Default)]
+pub(super) struct CStrings(Vec<CString>);
+
+impl CStrings {
+    // Returns a pointer to a new C string, or null if `s` is empty.
+    pub fn ptr(&mut self, s: &str) -> Result<*const c_char> {
+        if s.is_empty() {
+            Ok(null())
+        } else {
+            let cs = CString::new(s).or_else(|_| Error::msg("Unexpected NUL character."))?;
+            let ptr = cs.as_ptr();
+            self.0.push(cs);
+            Ok(ptr)
+        }
+    }
+
+    // Returns a pointer to a new C string, even if `s` is empty.
+    pub fn ptr_non_null(&mut self, s: &str) -> Result<*const c_char> {
+        let cs = CString::new(s).or_else(|_| Error::msg("Unexpected NUL character."))?;
+        let ptr = cs.as_ptr();
+        self.0.push(cs);
+        Ok(ptr)
+    }
+}
@@ -0,0 +1,63 @@
+// notcurses::widgets::selector::item
+//
+//!
+//
+
+/// An item of a [`Selector`] or a [`MultiSelector`], with an optional payload.
+///
+/// [`Selector`]: crate::Selector
+/// [`MultiSelector`]: crate::MultiSelector
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectorItem<T = ()> {
+    /// The option, shown on the left.
+    pub option: String,
+
+    /// The description, shown on the right.
+    pub desc: String,
+
+    /// An optional payload associated to the item.
+    pub payload: Option<T>,
+}
+
+mod core_impls {
+    use super::SelectorItem;
+
+    impl<T> From<(String, String)> for SelectorItem<T> {
+        fn from((option, desc): (String, String)) -> Self {
+            Self::new(&option, &desc)
+        }
+    }
+
+    impl<T> From<(&str, &str)> for SelectorItem<T> {
+        fn from((option, desc): (&str, &str)) -> Self {
+            Self::new(option, desc)
+        }
+    }
+
+    impl<T> From<(&str, &str, T)> for SelectorItem<T> {
+        fn from((option, desc, payload): (&str, &str, T)) -> Self {
+            Self::new(option, desc).payload(payload)
+        }
+    }
+}
+
+/// # constructors
+impl<T> SelectorItem<T> {
+    /// Returns a new item without a payload.
+    pub fn new(option: &str, desc: &str) -> Self {
+        Self {
+            option: option.into(),
+            desc: desc.into(),
+            payload: None,
+        }
+    }
+}
+
+/// # methods (chainable)
+impl<T> SelectorItem<T> {
+    /// Sets the `payload` of the item.
+    pub fn payload(mut self, payload: T) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+}
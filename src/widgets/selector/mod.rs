@@ -0,0 +1,17 @@
+// notcurses::widgets::selector
+//
+//!
+//
+
+mod builder;
+mod common;
+mod item;
+mod multi_builder;
+mod multiselector;
+mod selector;
+
+pub use builder::SelectorBuilder;
+pub use item::SelectorItem;
+pub use multi_builder::MultiSelectorBuilder;
+pub use multiselector::MultiSelector;
+pub use selector::{Selector, SelectorAction};
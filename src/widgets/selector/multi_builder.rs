@@ -0,0 +1,201 @@
+// notcurses::widgets::selector::multi_builder
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::{Plane, Style},
+    sys::{
+        c_api,
+        widgets::{NcMultiSelectorItem, NcMultiSelectorOptions},
+    },
+    widgets::selector::{
+        common::{CStrings, Common},
+        MultiSelector, SelectorItem,
+    },
+    Position,
+};
+use core::ptr::null;
+
+/// A [`MultiSelector`] builder.
+#[derive(Clone, Debug)]
+pub struct MultiSelectorBuilder<T = ()> {
+    items: Vec<(SelectorItem<T>, bool)>,
+    common: Common,
+}
+
+mod core_impls {
+    use super::MultiSelectorBuilder;
+
+    impl<T> Default for MultiSelectorBuilder<T> {
+        fn default() -> Self {
+            Self {
+                items: vec![],
+                common: Default::default(),
+            }
+        }
+    }
+}
+
+/// # constructors
+impl<T> MultiSelectorBuilder<T> {
+    /// Returns a new default `MultiSelectorBuilder`, without items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `MultiSelector` drawn on a new child plane of the provided `parent`.
+    ///
+    /// # Errors
+    /// - if there are no items.
+    /// - if any string contains a NUL character.
+    /// - if notcurses fails to create the selector.
+    pub fn build(self, parent: &mut Plane) -> Result<MultiSelector<T>> {
+        if self.items.is_empty() {
+            return Error::msg("A `MultiSelector` must have at least one item.");
+        }
+
+        let mut strings = CStrings::default();
+        let mut nc_items = vec![];
+        for (item, selected) in &self.items {
+            nc_items.push(NcMultiSelectorItem {
+                option: strings.ptr_non_null(&item.option)?,
+                desc: strings.ptr_non_null(&item.desc)?,
+                selected: *selected,
+            });
+        }
+        // the list of items is terminated by an item with a null option.
+        nc_items.push(NcMultiSelectorItem {
+            option: null(),
+            desc: null(),
+            selected: false,
+        });
+
+        let c = &self.common;
+        let options = NcMultiSelectorOptions {
+            title: strings.ptr(&c.title)?,
+            secondary: strings.ptr(&c.secondary)?,
+            footer: strings.ptr(&c.footer)?,
+            items: nc_items.as_ptr(),
+            maxdisplay: c.max_display,
+            opchannels: c.option_channels.nc.into(),
+            descchannels: c.desc_channels.nc.into(),
+            titlechannels: c.title_channels.nc.into(),
+            footchannels: c.footer_channels.nc.into(),
+            boxchannels: c.box_channels.nc.into(),
+            flags: 0,
+        };
+
+        // the selector takes ownership of the plane, even if it fails to be created.
+        let ncplane = c.plane(parent)?;
+        let ncmultiselector = unsafe { c_api::ncmultiselector_create(ncplane, &options) };
+
+        if ncmultiselector.is_null() {
+            Error::msg("Couldn't create the `MultiSelector`.")
+        } else {
+            Ok(MultiSelector {
+                nc: ncmultiselector,
+                items: self.items.into_iter().map(|(item, _)| item).collect(),
+            })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl<T> MultiSelectorBuilder<T> {
+    /// Adds an `item` after the existing ones, initially `selected` or not.
+    pub fn item(mut self, item: impl Into<SelectorItem<T>>, selected: bool) -> Self {
+        self.items.push((item.into(), selected));
+        self
+    }
+
+    /// Adds multiple unselected `items` after the existing ones.
+    pub fn items<I: Into<SelectorItem<T>>>(mut self, items: impl IntoIterator<Item = I>) -> Self {
+        self.items
+            .extend(items.into_iter().map(|item| (item.into(), false)));
+        self
+    }
+
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *`(0, 0)`*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.common.position = position.into();
+        self
+    }
+
+    /// Sets the title, shown above the items.
+    ///
+    /// Default: *`""`*.
+    pub fn title(mut self, title: &str) -> Self {
+        self.common.title = title.into();
+        self
+    }
+
+    /// Sets the secondary title, shown in the upper border.
+    ///
+    /// Default: *`""`*.
+    pub fn secondary(mut self, secondary: &str) -> Self {
+        self.common.secondary = secondary.into();
+        self
+    }
+
+    /// Sets the footer, shown in the lower border.
+    ///
+    /// Default: *`""`*.
+    pub fn footer(mut self, footer: &str) -> Self {
+        self.common.footer = footer.into();
+        self
+    }
+
+    /// Sets the maximum number of items shown at once, or `0` for no limit.
+    ///
+    /// Default: *`0`*.
+    pub fn max_display(mut self, max_display: u32) -> Self {
+        self.common.max_display = max_display;
+        self
+    }
+
+    /// Sets the channels of the options and of their descriptions.
+    ///
+    /// Default: *`Channels::default()`* for both.
+    pub fn item_channels(mut self, option: impl Into<Channels>, desc: impl Into<Channels>) -> Self {
+        self.common.option_channels = option.into();
+        self.common.desc_channels = desc.into();
+        self
+    }
+
+    /// Sets the channels of the title.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn title_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.common.title_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the secondary title and the footer.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn footer_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.common.footer_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the border.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn box_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.common.box_channels = channels.into();
+        self
+    }
+
+    /// Sets the text style.
+    ///
+    /// Default: *[`Style::None`]*.
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.common.style = style.into();
+        self
+    }
+}
@@ -0,0 +1,125 @@
+// notcurses::widgets::selector::multiselector
+//
+//!
+//
+
+use crate::{
+    input::Input,
+    sys::{c_api, widgets::NcMultiSelector},
+    widgets::selector::{MultiSelectorBuilder, SelectorItem},
+};
+
+/// A list of items, of which any number can be selected.
+///
+/// Items are toggled with the space key or by clicking over them.
+///
+/// It's drawn on its own plane, which is resized to fit the items, and is
+/// destroyed together with the selector.
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// # nc.inject_inputs([Input::from(Key::Down), Input::from(' '), Input::from(Key::Enter)]);
+/// let mut cli = nc.cli_plane()?;
+/// let mut selector = MultiSelector::<()>::builder()
+///     .title("toppings")
+///     .item(("cheese", "mozzarella"), true)
+///     .item(("olives", "black"), false)
+///     .build(&mut cli)?;
+/// loop {
+///     cli.render()?;
+///     let input = nc.get_event()?;
+///     if input.is_key(Key::Enter) {
+///         break;
+///     }
+///     selector.offer_input(&input);
+/// }
+/// for item in selector.selected() {
+///     println!("{}", item.option);
+/// }
+/// # assert_eq![vec![true, true], selector.selection()];
+/// # Ok(())
+/// # }
+/// ```
+pub struct MultiSelector<T = ()> {
+    pub(super) nc: *mut NcMultiSelector,
+    pub(super) items: Vec<SelectorItem<T>>,
+}
+
+mod core_impls {
+    use super::MultiSelector;
+    use core::fmt;
+
+    impl<T> Drop for MultiSelector<T> {
+        fn drop(&mut self) {
+            if crate::Notcurses::is_initialized() {
+                unsafe { crate::sys::c_api::ncmultiselector_destroy(self.nc) };
+            }
+        }
+    }
+
+    impl<T> fmt::Debug for MultiSelector<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "MultiSelector {{ items: {}, selected: {:?} }}",
+                self.items.len(),
+                self.selected().map(|i| &i.option).collect::<Vec<_>>()
+            )
+        }
+    }
+}
+
+/// # constructors
+impl<T> MultiSelector<T> {
+    /// Returns a new [`MultiSelectorBuilder`].
+    pub fn builder() -> MultiSelectorBuilder<T> {
+        MultiSelectorBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcMultiSelector`].
+    pub fn into_ref(&self) -> &NcMultiSelector {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcMultiSelector`].
+    pub fn into_ref_mut(&mut self) -> &mut NcMultiSelector {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl<T> MultiSelector<T> {
+    /// Returns the items of the selector.
+    pub fn items(&self) -> &[SelectorItem<T>] {
+        &self.items
+    }
+
+    /// Returns the selection state of each item, in order.
+    pub fn selection(&self) -> Vec<bool> {
+        let mut selected = vec![false; self.items.len()];
+        unsafe {
+            c_api::ncmultiselector_selected(self.nc, selected.as_mut_ptr(), selected.len() as _)
+        };
+        selected
+    }
+
+    /// Returns an iterator over the selected items, in order.
+    pub fn selected(&self) -> impl Iterator<Item = &SelectorItem<T>> {
+        self.items
+            .iter()
+            .zip(self.selection())
+            .filter_map(|(item, selected)| selected.then_some(item))
+    }
+
+    /// Offers an `input` to the selector.
+    ///
+    /// Returns `true` if it was consumed, e.g. for navigation or toggling an item.
+    pub fn offer_input(&mut self, input: &Input) -> bool {
+        unsafe { c_api::ncmultiselector_offer_input(self.nc, &input.into()) }
+    }
+}
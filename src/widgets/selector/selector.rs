@@ -0,0 +1,148 @@
+// notcurses::widgets::selector::selector
+//
+//!
+//
+
+use crate::{
+    input::{Input, Key, KeyMod},
+    sys::{c_api, widgets::NcSelector},
+    widgets::selector::{SelectorBuilder, SelectorItem},
+};
+use core::ffi::{c_char, CStr};
+
+/// A list of items, of which one is selected.
+///
+/// It's drawn on its own plane, which is resized to fit the items, and is
+/// destroyed together with the selector.
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// # nc.inject_inputs([Key::Down, Key::Enter]);
+/// let mut cli = nc.cli_plane()?;
+/// let mut selector = Selector::builder()
+///     .title("pick a fruit")
+///     .item(("apple", "red and crunchy", 1))
+///     .item(("banana", "yellow and soft", 2))
+///     .build(&mut cli)?;
+/// loop {
+///     cli.render()?;
+///     if let Some(SelectorAction::Selected(item)) = selector.offer_input(&nc.get_event()?) {
+///         println!("{:?}", item.payload);
+/// #       assert_eq![Some(2), item.payload];
+///         break;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Selector<T = ()> {
+    pub(super) nc: *mut NcSelector,
+    pub(super) items: Vec<SelectorItem<T>>,
+}
+
+/// The result of offering an [`Input`] to a [`Selector`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectorAction<'s, T> {
+    /// The input was consumed by the selector (e.g. for navigation).
+    Consumed,
+
+    /// The highlighted item was selected by pressing [`Enter`][Key::Enter].
+    Selected(&'s SelectorItem<T>),
+}
+
+mod core_impls {
+    use super::Selector;
+    use core::{fmt, ptr::null_mut};
+
+    impl<T> Drop for Selector<T> {
+        fn drop(&mut self) {
+            if crate::Notcurses::is_initialized() {
+                unsafe { crate::sys::c_api::ncselector_destroy(self.nc, null_mut()) };
+            }
+        }
+    }
+
+    impl<T> fmt::Debug for Selector<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "Selector {{ items: {}, selected: {:?} }}",
+                self.items.len(),
+                self.selected().map(|i| &i.option)
+            )
+        }
+    }
+}
+
+/// # constructors
+impl<T> Selector<T> {
+    /// Returns a new [`SelectorBuilder`].
+    pub fn builder() -> SelectorBuilder<T> {
+        SelectorBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcSelector`].
+    pub fn into_ref(&self) -> &NcSelector {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcSelector`].
+    pub fn into_ref_mut(&mut self) -> &mut NcSelector {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl<T> Selector<T> {
+    /// Returns the items of the selector.
+    pub fn items(&self) -> &[SelectorItem<T>] {
+        &self.items
+    }
+
+    /// Returns the selected item, or `None` if there are no items.
+    pub fn selected(&self) -> Option<&SelectorItem<T>> {
+        self.find(unsafe { c_api::ncselector_selected(self.nc) })
+    }
+
+    /// Selects the next item, and returns it.
+    pub fn next_item(&mut self) -> Option<&SelectorItem<T>> {
+        self.find(unsafe { c_api::ncselector_nextitem(self.nc) })
+    }
+
+    /// Selects the previous item, and returns it.
+    pub fn prev_item(&mut self) -> Option<&SelectorItem<T>> {
+        self.find(unsafe { c_api::ncselector_previtem(self.nc) })
+    }
+
+    /// Offers an `input` to the selector.
+    ///
+    /// Returns `None` if the input was not relevant to the selector.
+    pub fn offer_input(&mut self, input: &Input) -> Option<SelectorAction<'_, T>> {
+        // the locks shouldn't prevent selecting the item.
+        let keymod = input.keymod & !(KeyMod::CapsLock | KeyMod::NumLock);
+        if input.is_key(Key::Enter) && keymod.has_none() && !input.is_release() {
+            return self.selected().map(SelectorAction::Selected);
+        }
+        if unsafe { c_api::ncselector_offer_input(self.nc, &input.into()) } {
+            Some(SelectorAction::Consumed)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Selector<T> {
+    // Returns the item whose option matches the C string.
+    fn find(&self, option: *const c_char) -> Option<&SelectorItem<T>> {
+        if option.is_null() {
+            return None;
+        }
+        let option = unsafe { CStr::from_ptr(option) }.to_string_lossy();
+        self.items.iter().find(|i| i.option == option)
+    }
+}
@@ -0,0 +1,151 @@
+// notcurses::widgets::tabbed::builder
+//
+//!
+//
+
+use crate::{
+    color::Channels,
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{c_api, widgets::NcTabbedOptions, NcPlane},
+    widgets::tabbed::Tabbed,
+    Position, Size,
+};
+use core::ptr::null;
+use std::ffi::CString;
+
+/// A [`Tabbed`] builder.
+#[derive(Clone, Debug, Default)]
+pub struct TabbedBuilder {
+    position: Position,
+    size: Option<Size>,
+    header_channels: Channels,
+    selected_channels: Channels,
+    separator_channels: Channels,
+    separator: String,
+    bottom: bool,
+}
+
+/// # constructors
+impl TabbedBuilder {
+    /// Returns a new default `TabbedBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new `Tabbed` drawn on a new child plane of the provided `parent`,
+    /// without tabs.
+    ///
+    /// # Errors
+    /// - if the separator contains a NUL character.
+    /// - if notcurses fails to create the widget.
+    pub fn build(self, parent: &mut Plane) -> Result<Tabbed> {
+        let separator = if self.separator.is_empty() {
+            None
+        } else {
+            Some(
+                CString::new(self.separator)
+                    .or_else(|_| Error::msg("The separator can't contain NUL characters."))?,
+            )
+        };
+        let options = NcTabbedOptions {
+            selchan: self.selected_channels.nc.into(),
+            hdrchan: self.header_channels.nc.into(),
+            sepchan: self.separator_channels.nc.into(),
+            separator: separator.as_ref().map_or(null(), |s| s.as_ptr()),
+            flags: if self.bottom {
+                NcTabbedOptions::BOTTOM as u64
+            } else {
+                0
+            },
+        };
+
+        let size = self.size.unwrap_or_else(|| {
+            let (width, height): (i32, i32) = parent.size().into();
+            Size::new(
+                (width - self.position.x()).max(1),
+                (height - self.position.y()).max(2),
+            )
+        });
+        let mut plane = Plane::builder()
+            .position(self.position)
+            .size(size)
+            .build_child(parent)?;
+
+        // the widget takes ownership of the plane, even if it fails to be created.
+        let ncplane: *mut NcPlane = plane.into_ref_mut();
+        core::mem::forget(plane);
+        let nctabbed = unsafe { c_api::nctabbed_create(ncplane, &options) };
+
+        if nctabbed.is_null() {
+            Error::msg("Couldn't create the `Tabbed`.")
+        } else {
+            Ok(Tabbed {
+                nc: nctabbed,
+                tabs: vec![],
+                next_id: 0,
+            })
+        }
+    }
+}
+
+/// # methods (chainable)
+impl TabbedBuilder {
+    /// Sets the position relative to the parent plane.
+    ///
+    /// Default: *`(0, 0)`*.
+    pub fn position(mut self, position: impl Into<Position>) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Sets the size of the widget, including the row of tab headers.
+    ///
+    /// Default: *the rest of the parent plane, from the position*.
+    pub fn size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the channels of the unselected tab headers.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn header_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.header_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the selected tab header.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn selected_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.selected_channels = channels.into();
+        self
+    }
+
+    /// Sets the channels of the separator.
+    ///
+    /// Default: *`Channels::default()`*.
+    pub fn separator_channels(mut self, channels: impl Into<Channels>) -> Self {
+        self.separator_channels = channels.into();
+        self
+    }
+
+    /// Sets the separator drawn between the tab headers.
+    ///
+    /// An empty separator means no separator.
+    ///
+    /// Default: *`""`*.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Draws the tab headers at the bottom, instead of at the top.
+    ///
+    /// Default: *`false`*.
+    pub fn bottom(mut self, bottom: bool) -> Self {
+        self.bottom = bottom;
+        self
+    }
+}
@@ -0,0 +1,12 @@
+// notcurses::widgets::tabbed
+//
+//!
+//
+
+mod builder;
+mod tab;
+mod tabbed;
+
+pub use builder::TabbedBuilder;
+pub use tab::{TabCallback, TabId};
+pub use tabbed::Tabbed;
@@ -0,0 +1,69 @@
+// notcurses::widgets::tabbed::tab
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    plane::Plane,
+    sys::{c_api, widgets::NcTab, NcPlane},
+};
+use core::{ffi::c_void, mem::ManuallyDrop};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// A closure that draws the contents of a tab of a [`Tabbed`] on its plane.
+///
+/// It's called each time the tab is redrawn while selected.
+///
+/// # Used by
+/// - [`Tabbed.add`][crate::Tabbed#method.add]
+///
+/// [`Tabbed`]: crate::Tabbed
+pub type TabCallback = Box<dyn FnMut(&mut Plane) -> Result<()>>;
+
+/// Identifies a tab in a [`Tabbed`][crate::Tabbed].
+///
+/// Identifiers are never reused by the same widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TabId(pub(super) u64);
+
+// The Rust side of a tab, stored in its userptr.
+pub(super) struct TabData {
+    pub plane: Plane,
+    pub draw: TabCallback,
+    pub error: Option<Error>,
+}
+
+impl TabData {
+    // Detaches the plane from the widget, making it the root of its own pile.
+    pub fn detach(&mut self) {
+        let ncplane: *mut NcPlane = self.plane.into_ref_mut();
+        unsafe { c_api::ncplane_reparent_family(ncplane, ncplane) };
+    }
+}
+
+// The C callback that shows the plane of the selected tab and draws it.
+pub(super) unsafe extern "C" fn trampoline(
+    _nctab: *mut NcTab,
+    content: *mut NcPlane,
+    userptr: *mut c_void,
+) {
+    let data = &mut *(userptr as *mut TabData);
+
+    let res = catch_unwind(AssertUnwindSafe(|| -> Result<()> {
+        // the content plane is owned by the widget, so it must not be destroyed here.
+        let size = ManuallyDrop::new(Plane::from(&mut *content)).size();
+        if data.plane.size() != size {
+            data.plane.resize_simple(size)?;
+        }
+        let ncplane: *mut NcPlane = data.plane.into_ref_mut();
+        c_api::ncplane_reparent_family(ncplane, content);
+        data.plane.move_to((0, 0))?;
+        (data.draw)(&mut data.plane)
+    }));
+
+    data.error = match res {
+        Ok(res) => res.err(),
+        Err(_) => Some(Error::Message("The tab callback panicked.".into())),
+    };
+}
@@ -0,0 +1,404 @@
+// notcurses::widgets::tabbed::tabbed
+//
+//!
+//
+
+use crate::{
+    error::{NotcursesError as Error, NotcursesResult as Result},
+    input::{Input, Key, KeyMod},
+    plane::Plane,
+    sys::{
+        c_api::{self, NCRESULT_ERR},
+        widgets::{NcTab, NcTabbed},
+    },
+    widgets::tabbed::{
+        tab::{trampoline, TabData},
+        TabId, TabbedBuilder,
+    },
+};
+use core::{
+    ffi::{c_void, CStr},
+    mem::ManuallyDrop,
+    ptr::null_mut,
+};
+use std::ffi::CString;
+
+/// A row of tab headers over a content area, showing the selected tab.
+///
+/// Each tab owns a [`Plane`] with its contents and a [`TabCallback`] that
+/// draws on it. The plane of the selected tab is shown in the content area,
+/// resized to fit, while the others are kept detached in their own piles,
+/// so they can be drawn on at any time.
+///
+/// All the methods that change the tabs or the selection redraw the widget.
+///
+/// [`TabCallback`]: crate::TabCallback
+///
+/// # Example
+/// ```
+/// # use notcurses::*;
+/// # fn main() -> NotcursesResult<()> {
+/// # let mut nc = Notcurses::headless((80, 24))?;
+/// # nc.inject_inputs([Key::Right, Key::Esc]);
+/// let mut stdplane = Plane::new(&mut nc)?;
+/// let mut tabbed = Tabbed::builder().separator(" | ").build(&mut stdplane)?;
+/// tabbed.add("first", |p| { p.putstr_at((0, 0), "first tab")?; Ok(()) })?;
+/// let second = tabbed.add("second", |p| { p.putstr_at((0, 0), "second tab")?; Ok(()) })?;
+/// loop {
+///     stdplane.render()?;
+///     let input = nc.get_event()?;
+///     if input.is_key(Key::Esc) {
+///         break;
+///     }
+///     tabbed.offer_input(&input)?;
+/// }
+/// # assert_eq![Some(second), tabbed.selected()];
+/// # Ok(())
+/// # }
+/// ```
+pub struct Tabbed {
+    pub(super) nc: *mut NcTabbed,
+    pub(super) tabs: Vec<(TabId, *mut NcTab, *mut TabData)>,
+    pub(super) next_id: u64,
+}
+
+mod core_impls {
+    use super::Tabbed;
+    use core::fmt;
+
+    impl Drop for Tabbed {
+        fn drop(&mut self) {
+            // the tab planes are destroyed before the widget planes.
+            for (_, _, data) in self.tabs.drain(..) {
+                drop(unsafe { Box::from_raw(data) });
+            }
+            if crate::Notcurses::is_initialized() {
+                unsafe { crate::sys::c_api::nctabbed_destroy(self.nc) };
+            }
+        }
+    }
+
+    impl fmt::Debug for Tabbed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "Tabbed {{ tabs: {}, selected: {:?} }}",
+                self.len(),
+                self.selected()
+            )
+        }
+    }
+}
+
+/// # constructors
+impl Tabbed {
+    /// Returns a new [`TabbedBuilder`].
+    pub fn builder() -> TabbedBuilder {
+        TabbedBuilder::new()
+    }
+
+    //
+
+    /// Returns a shared reference to the inner [`NcTabbed`].
+    pub fn into_ref(&self) -> &NcTabbed {
+        unsafe { &*self.nc }
+    }
+
+    /// Returns an exclusive reference to the inner [`NcTabbed`].
+    pub fn into_ref_mut(&mut self) -> &mut NcTabbed {
+        unsafe { &mut *self.nc }
+    }
+}
+
+/// # methods
+impl Tabbed {
+    /// Returns the number of tabs.
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Returns `true` if there are no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Returns the identifiers of all the tabs, from the leftmost one.
+    pub fn tab_ids(&self) -> Vec<TabId> {
+        let mut ids = vec![];
+        let first = unsafe { c_api::nctabbed_leftmost(self.nc) };
+        let mut nctab = first;
+        while let Some(id) = self.find_id(nctab) {
+            ids.push(id);
+            nctab = unsafe { c_api::nctab_next(nctab) };
+            if nctab == first {
+                break;
+            }
+        }
+        ids
+    }
+
+    /// Adds a new tab after the selected one, and redraws the widget.
+    ///
+    /// The tab gets a new plane, which is drawn by the `draw` callback
+    /// each time the tab is redrawn while selected.
+    ///
+    /// The first tab added gets selected.
+    ///
+    /// # Errors
+    /// - if the `name` is empty or contains a NUL character.
+    pub fn add(
+        &mut self,
+        name: &str,
+        draw: impl FnMut(&mut Plane) -> Result<()> + 'static,
+    ) -> Result<TabId> {
+        let cname = Self::cstring(name)?;
+
+        let mut content = self.content_plane();
+        let plane = Plane::builder()
+            .size(content.size())
+            .build_child(&mut content)?;
+        let mut data = TabData {
+            plane,
+            draw: Box::new(draw),
+            error: None,
+        };
+        // the plane starts detached from the widget.
+        data.detach();
+        let userptr = Box::into_raw(Box::new(data));
+
+        let nctab = unsafe {
+            c_api::nctabbed_add(
+                self.nc,
+                null_mut(),
+                null_mut(),
+                Some(trampoline),
+                cname.as_ptr(),
+                userptr as *mut c_void,
+            )
+        };
+        if nctab.is_null() {
+            drop(unsafe { Box::from_raw(userptr) });
+            return Error::msg("Couldn't add the tab to the `Tabbed`.");
+        }
+
+        let id = TabId(self.next_id);
+        self.next_id += 1;
+        self.tabs.push((id, nctab, userptr));
+        self.redraw()?;
+        Ok(id)
+    }
+
+    /// Removes the tab with the provided `id`, returning its plane,
+    /// and redraws the widget.
+    ///
+    /// Returns `None` if there's no such tab.
+    pub fn remove(&mut self, id: TabId) -> Result<Option<Plane>> {
+        let index = match self.tabs.iter().position(|(i, _, _)| *i == id) {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let (_, nctab, data) = self.tabs.remove(index);
+        unsafe { c_api::nctabbed_del(self.nc, nctab) };
+
+        let mut data = unsafe { Box::from_raw(data) };
+        data.detach();
+        self.redraw()?;
+        Ok(Some(data.plane))
+    }
+
+    /// Returns the identifier of the selected tab, if there are any.
+    pub fn selected(&self) -> Option<TabId> {
+        self.find_id(unsafe { c_api::nctabbed_selected(self.nc) })
+    }
+
+    /// Selects the tab with the provided `id`, and redraws the widget.
+    ///
+    /// Returns `false` if there's no such tab.
+    pub fn select(&mut self, id: TabId) -> Result<bool> {
+        match self.find_nctab(id) {
+            Some(nctab) => {
+                unsafe { c_api::nctabbed_select(self.nc, nctab) };
+                self.redraw()?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Selects the tab to the right of the selected one, wrapping around,
+    /// and redraws the widget.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Option<TabId>> {
+        let id = self.find_id(unsafe { c_api::nctabbed_next(self.nc) });
+        self.redraw()?;
+        Ok(id)
+    }
+
+    /// Selects the tab to the left of the selected one, wrapping around,
+    /// and redraws the widget.
+    pub fn prev(&mut self) -> Result<Option<TabId>> {
+        let id = self.find_id(unsafe { c_api::nctabbed_prev(self.nc) });
+        self.redraw()?;
+        Ok(id)
+    }
+
+    /// Rotates the tab headers `amount` tabs to the right, or to the left
+    /// if negative, and redraws the widget.
+    ///
+    /// The selected tab doesn't change.
+    pub fn rotate(&mut self, amount: i32) -> Result<()> {
+        unsafe { c_api::nctabbed_rotate(self.nc, amount) };
+        self.redraw()
+    }
+
+    /// Moves the tab with the provided `id` one place to the left,
+    /// wrapping around, and redraws the widget.
+    pub fn move_left(&mut self, id: TabId) -> Result<()> {
+        if let Some(nctab) = self.find_nctab(id) {
+            unsafe { c_api::nctab_move_left(self.nc, nctab) };
+        }
+        self.redraw()
+    }
+
+    /// Moves the tab with the provided `id` one place to the right,
+    /// wrapping around, and redraws the widget.
+    pub fn move_right(&mut self, id: TabId) -> Result<()> {
+        if let Some(nctab) = self.find_nctab(id) {
+            unsafe { c_api::nctab_move_right(self.nc, nctab) };
+        }
+        self.redraw()
+    }
+
+    /// Returns the name of the tab with the provided `id`.
+    pub fn name(&self, id: TabId) -> Option<String> {
+        let nctab = self.find_nctab(id)?;
+        let name = unsafe { CStr::from_ptr(c_api::nctab_name(nctab)) };
+        Some(name.to_string_lossy().into_owned())
+    }
+
+    /// Renames the tab with the provided `id`, and redraws the widget.
+    ///
+    /// # Errors
+    /// - if there's no such tab.
+    /// - if the `name` is empty or contains a NUL character.
+    pub fn rename(&mut self, id: TabId, name: &str) -> Result<()> {
+        let nctab = match self.find_nctab(id) {
+            Some(nctab) => nctab,
+            None => return Error::msg(&format!["There's no tab {id:?}."]),
+        };
+        let cname = Self::cstring(name)?;
+        match unsafe { c_api::nctab_set_name(nctab, cname.as_ptr()) } {
+            NCRESULT_ERR => Error::msg(&format!["Couldn't rename the tab to {name:?}."]),
+            _ => self.redraw(),
+        }
+    }
+
+    /// Sets the separator drawn between the tab headers, and redraws the widget.
+    ///
+    /// An empty `separator` means no separator.
+    ///
+    /// # Errors
+    /// - if the `separator` contains a NUL character.
+    pub fn set_separator(&mut self, separator: &str) -> Result<()> {
+        let res = if separator.is_empty() {
+            unsafe { c_api::nctabbed_set_separator(self.nc, core::ptr::null()) }
+        } else {
+            let cseparator = CString::new(separator)
+                .or_else(|_| Error::msg("The separator can't contain NUL characters."))?;
+            unsafe { c_api::nctabbed_set_separator(self.nc, cseparator.as_ptr()) }
+        };
+        match res {
+            NCRESULT_ERR => Error::msg(&format!["Invalid separator {separator:?}."]),
+            _ => self.redraw(),
+        }
+    }
+
+    /// Returns a shared reference to the plane of the tab with the provided `id`.
+    pub fn tab_plane(&self, id: TabId) -> Option<&Plane> {
+        let (_, _, data) = self.tabs.iter().find(|(i, _, _)| *i == id)?;
+        Some(unsafe { &(**data).plane })
+    }
+
+    /// Returns an exclusive reference to the plane of the tab with the provided `id`.
+    pub fn tab_plane_mut(&mut self, id: TabId) -> Option<&mut Plane> {
+        let (_, _, data) = self.tabs.iter().find(|(i, _, _)| *i == id)?;
+        Some(unsafe { &mut (**data).plane })
+    }
+
+    /// Redraws the tab headers, and the selected tab by calling its callback.
+    ///
+    /// # Errors
+    /// - if the callback of the selected tab returns an error or panics.
+    pub fn redraw(&mut self) -> Result<()> {
+        // only the plane of the selected tab gets attached to the widget.
+        for (_, _, data) in &self.tabs {
+            unsafe { (**data).detach() };
+        }
+        unsafe {
+            c_api::nctabbed_ensure_selected_header_visible(self.nc);
+            c_api::nctabbed_redraw(self.nc);
+        }
+
+        let selected = unsafe { c_api::nctabbed_selected(self.nc) };
+        match self.tabs.iter().find(|(_, nc, _)| *nc == selected) {
+            Some((_, _, data)) => match unsafe { (**data).error.take() } {
+                Some(err) => Err(err),
+                None => Ok(()),
+            },
+            None => Ok(()),
+        }
+    }
+
+    /// Offers an `input` to the widget.
+    ///
+    /// The left and right arrow keys select the previous and next tabs.
+    ///
+    /// Returns `true` if the input was consumed.
+    pub fn offer_input(&mut self, input: &Input) -> Result<bool> {
+        // the locks shouldn't prevent switching tabs.
+        let keymod = input.keymod & !(KeyMod::CapsLock | KeyMod::NumLock);
+        if input.is_release() || !keymod.has_none() || self.is_empty() {
+            return Ok(false);
+        }
+        if input.is_key(Key::Left) {
+            self.prev()?;
+            Ok(true)
+        } else if input.is_key(Key::Right) {
+            self.next()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl Tabbed {
+    // Returns the plane where the selected tab is shown.
+    fn content_plane(&mut self) -> ManuallyDrop<Plane> {
+        let ncplane = unsafe { c_api::nctabbed_content_plane(self.nc) };
+        // the plane is owned by the widget, so it must not be destroyed here.
+        ManuallyDrop::new(Plane::from(unsafe { &mut *ncplane }))
+    }
+
+    fn cstring(name: &str) -> Result<CString> {
+        if name.is_empty() {
+            return Error::msg("The name of a tab can't be empty.");
+        }
+        CString::new(name)
+            .or_else(|_| Error::msg("The name of a tab can't contain NUL characters."))
+    }
+
+    fn find_id(&self, nctab: *mut NcTab) -> Option<TabId> {
+        self.tabs
+            .iter()
+            .find(|(_, nc, _)| *nc == nctab)
+            .map(|(id, _, _)| *id)
+    }
+
+    fn find_nctab(&self, id: TabId) -> Option<*mut NcTab> {
+        self.tabs
+            .iter()
+            .find(|(i, _, _)| *i == id)
+            .map(|(_, nc, _)| *nc)
+    }
+}
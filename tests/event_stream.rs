@@ -0,0 +1,51 @@
+// notcurses::tests::event_stream
+//
+//! Tests the asynchronous `EventStream`.
+//
+
+#![cfg(all(unix, feature = "tokio"))]
+
+use futures_core::Stream;
+use notcurses::*;
+use std::{
+    future::poll_fn,
+    pin::Pin,
+    sync::{Mutex, MutexGuard},
+};
+
+// notcurses keeps some global state, so the instances are created one at a time.
+static HEADLESS: Mutex<()> = Mutex::new(());
+
+fn serial() -> MutexGuard<'static, ()> {
+    HEADLESS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// Returns the next input of the stream.
+async fn next(events: &mut EventStream<'_>) -> NotcursesResult<Input> {
+    poll_fn(|cx| Pin::new(&mut *events).poll_next(cx))
+        .await
+        .expect("the stream ended")
+}
+
+#[test]
+fn injected_inputs() -> NotcursesResult<()> {
+    let _serial = serial();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()?;
+    runtime.block_on(async {
+        let mut nc = Notcurses::headless((80, 24))?;
+        nc.inject_inputs([Input::from(Key::Up), Input::from('q')]);
+
+        // the injected inputs are returned first, without waiting for the fd.
+        let mut events = nc.event_stream()?;
+        assert![next(&mut events).await?.is_key(Key::Up)];
+        assert![next(&mut events).await?.is_char('q')];
+        drop(events);
+
+        assert_eq![0, nc.injected_inputs()];
+        Ok(())
+    })
+}
@@ -0,0 +1,255 @@
+// notcurses::tests::headless
+//
+//! Tests that render to a headless `Notcurses`.
+//
+
+#![cfg(unix)]
+
+use notcurses::*;
+use std::{
+    sync::{Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+
+// notcurses keeps some global state, so the instances are created one at a time.
+static HEADLESS: Mutex<()> = Mutex::new(());
+
+fn serial() -> MutexGuard<'static, ()> {
+    HEADLESS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[test]
+fn build_headless() -> NotcursesResult<()> {
+    let _serial = serial();
+    let nc = NotcursesBuilder::new().build_headless((40, 10))?;
+    assert![nc.is_headless()];
+    assert_eq![Size::new(40, 10), nc.size()];
+    Ok(())
+}
+
+#[test]
+fn build_headless_invalid_size() {
+    let _serial = serial();
+    assert![Notcurses::headless((0, 24)).is_err()];
+    assert![Notcurses::headless((80, 70_000)).is_err()];
+}
+
+#[test]
+fn render_headless() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new_sized(&mut nc, (20, 2))?;
+    assert_eq![Size::new(20, 2), plane.size()];
+
+    plane.putstr("hello world")?;
+    plane.render()?;
+    assert![plane.contents()?.starts_with("hello world")];
+
+    let frame = plane.render_to_buffer()?;
+    assert![String::from_utf8_lossy(&frame).contains("hello world")];
+    Ok(())
+}
+
+#[test]
+fn headless_is_reusable() -> NotcursesResult<()> {
+    let _serial = serial();
+    for _ in 0..2 {
+        let mut nc = Notcurses::headless((80, 24))?;
+        let mut plane = Plane::new(&mut nc)?;
+        plane.putstr("again")?;
+        plane.render()?;
+    }
+    Ok(())
+}
+
+#[test]
+fn headless_excludes_direct() -> NotcursesResult<()> {
+    let _serial = serial();
+    let _nc = Notcurses::headless((80, 24))?;
+    assert![Notcurses::headless((80, 24)).is_err()];
+    match Direct::new() {
+        Err(NotcursesError::Message(message)) => assert![message.contains("`Notcurses`")],
+        other => panic!["expected the `Direct` lock error, got {other:?}"],
+    }
+    assert![!Direct::is_initialized()];
+    Ok(())
+}
+
+#[test]
+fn hit_test() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let transparent = Channel::from_rgb_alpha(0x000000, Alpha::Transparent);
+
+    let mut root = Plane::new(&mut nc)?;
+    let mut below = root.new_child_sized_at((10, 5), (2, 1))?;
+    let mut above = root.new_child_sized_at((10, 5), (6, 3))?;
+    above.set_base_bg(transparent)?;
+    above.putstr("x")?;
+
+    // the glyphs of the topmost plane are hit,
+    assert_eq![
+        Some((above.id(), Position::new(0, 0))),
+        root.hit_test((6, 3))
+    ];
+    // its transparent cells are skipped,
+    assert_eq![
+        Some((below.id(), Position::new(5, 2))),
+        root.hit_test((7, 3))
+    ];
+    assert_eq![
+        Some((root.id(), Position::new(14, 7))),
+        root.hit_test((14, 7))
+    ];
+    // and a base glyph is not transparent, even with a transparent background.
+    above.set_base(
+        ".",
+        Style::None,
+        Channels::combine(Channel::new(), transparent),
+    )?;
+    assert_eq![
+        Some((above.id(), Position::new(1, 0))),
+        root.hit_test((7, 3))
+    ];
+
+    // the z-order is respected.
+    below.move_top();
+    assert_eq![
+        Some((below.id(), Position::new(4, 2))),
+        root.hit_test((6, 3))
+    ];
+
+    // any plane of the pile can be used, and positions outside are not hit.
+    assert_eq![root.hit_test((8, 4)), above.hit_test((8, 4))];
+    assert_eq![None, root.hit_test((80, 0))];
+    assert_eq![None, root.hit_test((-1, 0))];
+
+    // the cli plane's pile is tested with `plane_at`.
+    let mut cli = nc.cli_plane()?;
+    let mut child = cli.new_child_sized_at((4, 1), (10, 10))?;
+    child.putstr("cli")?;
+    assert_eq![
+        Some((child.id(), Position::new(1, 0))),
+        nc.plane_at((11, 10))
+    ];
+    assert_eq![Some((cli.id(), Position::new(0, 0))), nc.plane_at((0, 0))];
+    Ok(())
+}
+
+#[test]
+fn gradient_zone() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new_sized(&mut nc, (20, 10))?;
+    let top = Channels::from_rgb(0xFFFFFF, 0x000088);
+    let bottom = Channels::from_rgb(0xFFFFFF, 0x8800FF);
+
+    // a zero width extends to the right edge.
+    let filled = plane.gradient((0, 0, 0, 3), " ", Style::None, top, top, bottom, bottom)?;
+    assert_eq![20 * 3, filled];
+    let snapshot = plane.snapshot()?;
+    for x in 0..20 {
+        assert![!snapshot.cell((x, 2)).unwrap().channels.bg().is_default()];
+        assert![snapshot.cell((x, 3)).unwrap().channels.bg().is_default()];
+    }
+
+    // a zero size fills up to the bottom right corner.
+    let (left, right) = (Channel::from_rgb(0x000000), Channel::from_rgb(0xFFFFFF));
+    assert_eq![
+        14 * 8,
+        plane.gradient2x1((6, 2, 0, 0), left, right, left, right)?
+    ];
+    let snapshot = plane.snapshot()?;
+    assert![!snapshot.cell((19, 9)).unwrap().channels.bg().is_default()];
+    assert![snapshot.cell((5, 9)).unwrap().channels.bg().is_default()];
+    Ok(())
+}
+
+// notcurses reads the input from the standard input when it's a terminal,
+// instead of from the headless terminal.
+fn stdin_is_terminal() -> bool {
+    unsafe { notcurses::sys::c_api::libc::isatty(0) == 1 }
+}
+
+// Polls for the next received extended event, for up to 5 seconds.
+fn next_event(nc: &mut Notcurses) -> NotcursesResult<Event> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        let event = nc.poll_extended_event()?;
+        if event.received() {
+            return Ok(event);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    panic!["no event received"];
+}
+
+#[test]
+fn paste_and_focus_events() -> NotcursesResult<()> {
+    if stdin_is_terminal() {
+        return Ok(());
+    }
+    let _serial = serial();
+    let mut nc = NotcursesBuilder::new()
+        .bracketed_paste(true)
+        .focus_events(true)
+        .build_headless((80, 24))?;
+    nc.write_headless_input(b"\x1b[200~a\nb\x1b[201~\x1b[I")?;
+
+    assert_eq![Event::Paste("a\nb".into()), next_event(&mut nc)?];
+    assert_eq![Event::FocusGained, next_event(&mut nc)?];
+    Ok(())
+}
+
+#[test]
+fn lone_esc_event() -> NotcursesResult<()> {
+    if stdin_is_terminal() {
+        return Ok(());
+    }
+    let _serial = serial();
+    let mut nc = NotcursesBuilder::new()
+        .focus_events(true)
+        .build_headless((80, 24))?;
+
+    // it's returned after the escape delay, since nothing follows it.
+    nc.write_headless_input(b"\x1b")?;
+    let event = next_event(&mut nc)?;
+    assert![event.input().map_or(false, |input| input.is_key(Key::Esc))];
+    Ok(())
+}
+
+#[test]
+fn input_fd_readiness() -> NotcursesResult<()> {
+    use notcurses::sys::c_api::libc;
+    use std::os::unix::io::AsRawFd;
+
+    if stdin_is_terminal() {
+        return Ok(());
+    }
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+
+    // the fd stays registered while the events are polled.
+    let fd = nc.input_fd()?;
+    let mut pollfd = libc::pollfd {
+        fd: fd.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    nc.write_headless_input(b"q")?;
+    assert_eq![1, unsafe { libc::poll(&mut pollfd, 1, 5000) }];
+    assert![pollfd.revents & libc::POLLIN != 0];
+
+    let input = nc.poll_event()?;
+    assert![input.is_char('q')];
+
+    // a timeout too long for a deadline blocks until the next input.
+    nc.write_headless_input(b"w")?;
+    let input = nc.poll_event_timeout(Duration::MAX)?;
+    assert![input.is_char('w')];
+    assert_eq![fd, nc.input_fd()?];
+    Ok(())
+}
@@ -0,0 +1,112 @@
+// notcurses::tests::session
+//
+//! Tests that record and replay the sessions of a headless `Notcurses`.
+//
+
+#![cfg(unix)]
+
+use notcurses::*;
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+    rc::Rc,
+    sync::{Mutex, MutexGuard},
+};
+
+// notcurses keeps some global state, so the instances are created one at a time.
+static HEADLESS: Mutex<()> = Mutex::new(());
+
+fn serial() -> MutexGuard<'static, ()> {
+    HEADLESS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// A writer into a shared buffer, that can be made to fail.
+#[derive(Clone, Default)]
+struct SharedWriter {
+    buffer: Rc<RefCell<Vec<u8>>>,
+    failing: Rc<RefCell<bool>>,
+}
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if *self.failing.borrow() {
+            return Err(io::Error::new(io::ErrorKind::Other, "failing writer"));
+        }
+        self.buffer.borrow_mut().write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn inputs() -> Vec<Input> {
+    let mut mouse = Input::mouse(Key::Button1, InputType::Press, (3, 4));
+    mouse.offset = Some(Position::new(5, 7));
+    vec![
+        Input::from(Key::Up),
+        Input::new(Key::F05, KeyMod::Ctrl | KeyMod::Alt),
+        mouse,
+        Input::from('ñ'),
+    ]
+}
+
+#[test]
+fn record_and_replay() -> NotcursesResult<()> {
+    let _serial = serial();
+    let writer = SharedWriter::default();
+    {
+        let mut nc = Notcurses::headless((80, 24))?;
+        nc.start_recording(writer.clone())?;
+        assert![nc.is_recording()];
+
+        let mut plane = Plane::new(&mut nc)?;
+        plane.putstr("recorded")?;
+        plane.render()?;
+
+        nc.inject_inputs(inputs());
+        for input in inputs() {
+            assert_eq![input, nc.get_event()?];
+        }
+        nc.stop_recording()?;
+        assert![!nc.is_recording()];
+    }
+
+    let buffer = writer.buffer.borrow();
+    assert![String::from_utf8_lossy(&buffer).contains("recorded")];
+
+    let replayer = Replayer::from_reader(&buffer[..])?;
+    assert_eq![Size::new(80, 24), replayer.size()];
+    let recorded: Vec<Input> = replayer.inputs().iter().map(|(_, i)| *i).collect();
+    assert_eq![inputs(), recorded];
+
+    let mut nc = Notcurses::headless(replayer.size())?;
+    nc.replay(&replayer);
+    for input in inputs() {
+        assert_eq![input, nc.get_event()?];
+    }
+    Ok(())
+}
+
+#[test]
+fn record_best_effort() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+
+    let writer = SharedWriter::default();
+    nc.start_recording(writer.clone())?;
+    *writer.failing.borrow_mut() = true;
+
+    // neither the input nor the rendering fail.
+    nc.inject_input('a');
+    assert![nc.get_event()?.is_char('a')];
+    plane.putstr("not recorded")?;
+    plane.render()?;
+
+    // the error is returned when stopping.
+    assert![!nc.is_recording()];
+    assert![nc.stop_recording().is_err()];
+    assert![nc.stop_recording().is_ok()];
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// notcurses::tests::snapshot
+//
+//! Tests that compare headless planes against their golden snapshots.
+//
+
+#![cfg(unix)]
+
+use notcurses::*;
+use std::sync::{Mutex, MutexGuard};
+
+// notcurses keeps some global state, so the instances are created one at a time.
+static HEADLESS: Mutex<()> = Mutex::new(());
+
+fn serial() -> MutexGuard<'static, ()> {
+    HEADLESS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[test]
+fn hello_world() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new_sized(&mut nc, (20, 3))?;
+    putstr!(plane, "hello world")?;
+    assert_plane_snapshot!(plane, "hello_world");
+    Ok(())
+}
+
+#[test]
+fn rounded_box() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new_sized(&mut nc, (20, 3))?;
+    plane.perimeter(
+        BoxStyle::Rounded,
+        Channels::new(),
+        Style::None,
+        BoxEdges::All,
+    )?;
+    assert_plane_snapshot!(plane, "rounded_box");
+    Ok(())
+}
+
+#[test]
+fn snapshot_cells() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new_sized(&mut nc, (4, 1))?;
+    plane.putstr("ab")?;
+
+    let snapshot = plane.snapshot()?;
+    assert_eq![Size::new(4, 1), snapshot.size()];
+    assert_eq!["a", snapshot.cell((0, 0)).unwrap().egc];
+    assert_eq!["b", snapshot.cell((1, 0)).unwrap().egc];
+    assert![snapshot.cell((2, 0)).unwrap().egc.is_empty()];
+    assert![snapshot.cell((4, 0)).is_none()];
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "plane snapshot mismatch")]
+fn mismatch() {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24)).unwrap();
+    let mut plane = Plane::new_sized(&mut nc, (20, 3)).unwrap();
+    plane.putstr("hello there").unwrap();
+    assert_plane_snapshot!(plane, "hello_world");
+}
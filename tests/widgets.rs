@@ -0,0 +1,424 @@
+// notcurses::tests::widgets
+//
+//! Tests the widgets on a headless `Notcurses`.
+//
+
+#![cfg(unix)]
+
+use notcurses::*;
+use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::{Mutex, MutexGuard},
+};
+
+// notcurses keeps some global state, so the instances are created one at a time.
+static HEADLESS: Mutex<()> = Mutex::new(());
+
+fn serial() -> MutexGuard<'static, ()> {
+    HEADLESS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[test]
+fn menu_selection() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+    let mut menu = Menu::builder()
+        .section(MenuSection::new(
+            "File",
+            vec![
+                MenuItem::new("Open", Input::new('o', KeyMod::Ctrl)),
+                MenuItem::separator(),
+                MenuItem::new("Quit", Input::new('q', KeyMod::Ctrl)),
+            ],
+            Input::new('f', KeyMod::Alt),
+        ))
+        .section(MenuSection::new(
+            "Help",
+            vec![MenuItem::without_shortcut("About")],
+            Input::new('h', KeyMod::Alt),
+        ))
+        .build(&mut plane)?;
+    let sections = menu.sections().to_vec();
+    let (open, quit, about) = (
+        sections[0].items[0].clone(),
+        sections[0].items[2].clone(),
+        sections[1].items[0].clone(),
+    );
+
+    // nothing is selected while the sections are rolled up.
+    assert_eq![None, menu.selected()];
+    assert_eq![None, menu.offer_input(&Input::from(Key::Enter))];
+
+    // the items are highlighted skipping the separators,
+    menu.unroll(0)?;
+    assert_eq![Some(open.clone()), menu.selected()];
+    menu.next_item()?;
+    assert_eq![Some(quit.clone()), menu.selected()];
+    menu.next_section()?;
+    assert_eq![Some(about.clone()), menu.selected()];
+
+    // and the highlighted one is selected with Enter, rolling up the menu.
+    menu.prev_section()?;
+    assert_eq![Some(open.clone()), menu.selected()];
+    let enter = Input::new(Key::Enter, KeyMod::NumLock);
+    assert_eq![Some(MenuAction::Selected(open)), menu.offer_input(&enter)];
+    assert_eq![None, menu.selected()];
+
+    // the shortcuts work while rolled up, unless the item is disabled.
+    let ctrl_q = Input::new('q', KeyMod::Ctrl);
+    assert_eq![
+        Some(MenuAction::Selected(quit.clone())),
+        menu.offer_input(&ctrl_q)
+    ];
+    menu.set_item_enabled("File", "Quit", false)?;
+    assert![!menu.is_item_enabled("File", "Quit")];
+    assert_ne![Some(MenuAction::Selected(quit)), menu.offer_input(&ctrl_q)];
+    assert![menu.set_item_enabled("File", "Nope", true).is_err()];
+
+    // the section shortcuts unroll them.
+    let alt_h = Input::new('h', KeyMod::Alt);
+    assert_eq![Some(MenuAction::Consumed), menu.offer_input(&alt_h)];
+    assert_eq![Some(about), menu.selected()];
+    Ok(())
+}
+
+#[test]
+fn progress_bar() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+    let mut bar = ProgressBar::builder()
+        .position((2, 3))
+        .length(20)
+        .color(Rgb::new(0, 255, 0))
+        .build(&mut plane)?;
+    assert_eq![0.0, bar.progress()];
+
+    bar.set_progress(0.5)?;
+    assert_eq![0.5, bar.progress()];
+    bar.set_progress(1.0)?;
+    assert_eq![1.0, bar.progress()];
+    plane.render()?;
+
+    // the progress is kept when setting an invalid one.
+    assert![bar.set_progress(1.5).is_err()];
+    assert![bar.set_progress(-0.1).is_err()];
+    assert![bar.set_progress(f64::NAN).is_err()];
+    assert_eq![1.0, bar.progress()];
+
+    let mut vertical = ProgressBar::builder()
+        .direction(ProgressDirection::BottomToTop)
+        .length(10)
+        .thickness(2)
+        .build(&mut plane)?;
+    vertical.set_progress(0.25)?;
+    assert_eq![0.25, vertical.progress()];
+
+    assert![ProgressBar::builder()
+        .length(2)
+        .thickness(2)
+        .build(&mut plane)
+        .is_err()];
+    Ok(())
+}
+
+#[test]
+fn plot_samples() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+    let mut plot = Plot::<u64>::builder()
+        .size((20, 4))
+        .rangex(10)
+        .title("load")
+        .build(&mut plane)?;
+
+    // the samples are accumulated, or replaced,
+    plot.add_sample(3, 5)?;
+    plot.add_sample(3, 2)?;
+    assert_eq![7, plot.sample(3)?];
+    plot.set_sample(3, 1)?;
+    assert_eq![1, plot.sample(3)?];
+    assert_eq![0, plot.sample(2)?];
+
+    // and the window slides forward, losing the samples left behind.
+    plot.add_sample(25, 9)?;
+    assert_eq![9, plot.sample(25)?];
+    assert![plot.sample(3).is_err()];
+    assert![plot.add_sample(3, 1).is_err()];
+    assert![plot.set_sample(3, 1).is_err()];
+    plane.render()?;
+
+    let mut plot = Plot::<f64>::builder()
+        .size((20, 4))
+        .domain(PlotDomain::Fixed { min: 0.0, max: 1.0 })
+        .build(&mut plane)?;
+    plot.add_sample(0, 0.25)?;
+    plot.add_sample(0, 0.5)?;
+    assert_eq![0.75, plot.sample(0)?];
+
+    let invalid = Plot::<f64>::builder()
+        .domain(PlotDomain::Fixed { min: 1.0, max: 1.0 })
+        .build(&mut plane);
+    assert![invalid.is_err()];
+    assert![Plot::<u64>::builder()
+        .title("a\0b")
+        .build(&mut plane)
+        .is_err()];
+    Ok(())
+}
+
+// A tablet that counts how many times it has been drawn.
+struct Counter(Rc<Cell<u32>>);
+
+impl Tablet for Counter {
+    fn draw(&mut self, plane: &mut Plane, _clip_top: bool) -> NotcursesResult<u32> {
+        self.0.set(self.0.get() + 1);
+        plane.putstr_at((0, 0), "tablet")?;
+        Ok(1)
+    }
+}
+
+#[test]
+fn reel_tablets() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+    let mut reel = Reel::builder().build(&mut plane)?;
+    assert![reel.is_empty()];
+    assert_eq![None, reel.focused()];
+
+    let draws = Rc::new(Cell::new(0));
+    let first = reel.add(Counter(draws.clone()))?;
+    let second = reel.add(Counter(draws.clone()))?;
+    let third = reel.add(Counter(draws.clone()))?;
+    assert_eq![3, reel.len()];
+    assert_eq![
+        vec![first, second, third],
+        reel.tablet_ids().collect::<Vec<_>>()
+    ];
+    assert![reel.tablet(second).is_some()];
+
+    // the first tablet gets the focus, which can be moved back and forth,
+    assert_eq![Some(first), reel.focused()];
+    let next = reel.next();
+    assert![next.is_some() && next != Some(first)];
+    assert_eq![next, reel.focused()];
+    assert_eq![Some(first), reel.prev()];
+    assert![reel.offer_input(&Input::from(Key::Down))];
+    assert_eq![next, reel.focused()];
+
+    // and the tablets are drawn.
+    draws.set(0);
+    reel.redraw()?;
+    assert![draws.get() > 0];
+    plane.render()?;
+
+    // deleting the focused tablet moves the focus to another one.
+    let focused = reel.focused().unwrap();
+    assert![reel.del(focused).is_some()];
+    assert![reel.del(focused).is_none()];
+    assert![reel.tablet(focused).is_none()];
+    assert_eq![2, reel.len()];
+    assert![reel.focused().is_some() && reel.focused() != Some(focused)];
+
+    for id in reel.tablet_ids().collect::<Vec<_>>() {
+        reel.del(id);
+    }
+    assert![reel.is_empty()];
+    assert_eq![None, reel.focused()];
+
+    // identifiers are not reused.
+    let fourth = reel.add(Counter(draws))?;
+    assert![![first, second, third].contains(&fourth)];
+    Ok(())
+}
+
+#[test]
+fn selector_navigation() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+    let mut selector = Selector::builder()
+        .title("fruits")
+        .items([
+            ("apple", "red", 1),
+            ("banana", "yellow", 2),
+            ("cherry", "red", 3),
+        ])
+        .default_item(1)
+        .build(&mut plane)?;
+    let payload = |item: Option<&SelectorItem<i32>>| item.and_then(|i| i.payload);
+    assert_eq![3, selector.items().len()];
+    assert_eq![Some(2), payload(selector.selected())];
+
+    // the selection moves with the methods, wrapping around,
+    assert_eq![Some(3), payload(selector.next_item())];
+    assert_eq![Some(1), payload(selector.next_item())];
+    assert_eq![Some(3), payload(selector.prev_item())];
+    assert_eq![Some(3), payload(selector.selected())];
+
+    // and with the arrow keys, until an item is selected with Enter.
+    assert_eq![
+        Some(SelectorAction::Consumed),
+        selector.offer_input(&Input::from(Key::Up))
+    ];
+    let enter = Input::new(Key::Enter, KeyMod::CapsLock);
+    match selector.offer_input(&enter) {
+        Some(SelectorAction::Selected(item)) => assert_eq!["banana", item.option],
+        other => panic!["expected a selection, got {other:?}"],
+    }
+    assert_eq![None, selector.offer_input(&Input::from(Key::F01))];
+    plane.render()?;
+    Ok(())
+}
+
+#[test]
+fn multiselector_toggling() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+    let mut selector = MultiSelector::<()>::builder()
+        .title("toppings")
+        .item(("cheese", "mozzarella"), true)
+        .item(("olives", "black"), false)
+        .item(("basil", "fresh"), false)
+        .build(&mut plane)?;
+    assert_eq![vec![true, false, false], selector.selection()];
+
+    // the space toggles the highlighted item.
+    assert![selector.offer_input(&Input::from(' '))];
+    assert![selector.offer_input(&Input::from(Key::Down))];
+    assert![selector.offer_input(&Input::from(Key::Down))];
+    assert![selector.offer_input(&Input::from(' '))];
+    assert_eq![vec![false, false, true], selector.selection()];
+    let options: Vec<_> = selector.selected().map(|i| i.option.as_str()).collect();
+    assert_eq![vec!["basil"], options];
+    plane.render()?;
+    Ok(())
+}
+
+#[test]
+fn tabbed_tabs() -> NotcursesResult<()> {
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+    let mut tabbed = Tabbed::builder().separator("|").build(&mut plane)?;
+    assert![tabbed.is_empty()];
+    assert_eq![None, tabbed.selected()];
+    assert![!tabbed.offer_input(&Input::from(Key::Right))?];
+
+    let draw = |text: &'static str| {
+        move |p: &mut Plane| {
+            p.putstr_at((0, 0), text)?;
+            Ok(())
+        }
+    };
+    // the first tab gets selected, and the next ones are added after it.
+    let first = tabbed.add("first", draw("first tab"))?;
+    let second = tabbed.add("second", draw("second tab"))?;
+    let third = tabbed.add("third", draw("third tab"))?;
+    assert_eq![3, tabbed.len()];
+    assert_eq![vec![first, third, second], tabbed.tab_ids()];
+    assert_eq![Some(first), tabbed.selected()];
+    assert![tabbed
+        .tab_plane_mut(first)
+        .unwrap()
+        .contents()?
+        .starts_with("first tab")];
+
+    // the selection changes with the methods and the arrow keys, wrapping around,
+    assert![tabbed.select(second)?];
+    assert![tabbed
+        .tab_plane_mut(second)
+        .unwrap()
+        .contents()?
+        .starts_with("second tab")];
+    assert_eq![Some(first), tabbed.next()?];
+    assert_eq![Some(second), tabbed.prev()?];
+    assert![tabbed.offer_input(&Input::new(Key::Left, KeyMod::NumLock))?];
+    assert_eq![Some(third), tabbed.selected()];
+    assert![!tabbed.offer_input(&Input::new(Key::Left, KeyMod::Ctrl))?];
+    assert_eq![Some(third), tabbed.selected()];
+
+    // and the tabs can be renamed,
+    assert_eq![Some("third".to_string()), tabbed.name(third)];
+    tabbed.rename(third, "3rd")?;
+    assert_eq![Some("3rd".to_string()), tabbed.name(third)];
+    assert![tabbed.rename(third, "").is_err()];
+    assert![tabbed.add("a\0b", draw("")).is_err()];
+    plane.render()?;
+
+    // or removed, returning their plane.
+    let mut removed = tabbed.remove(third)?.expect("the removed tab's plane");
+    assert![removed.contents()?.starts_with("third tab")];
+    assert![tabbed.remove(third)?.is_none()];
+    assert![!tabbed.select(third)?];
+    assert_eq![None, tabbed.name(third)];
+    assert_eq![vec![first, second], tabbed.tab_ids()];
+    assert![tabbed.selected().is_some() && tabbed.selected() != Some(third)];
+    Ok(())
+}
+
+#[test]
+fn fd_plane_output() -> NotcursesResult<()> {
+    use std::{io::Write, os::unix::net::UnixStream, sync::mpsc, time::Duration};
+
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+
+    let (mut writer, reader) = UnixStream::pair()?;
+    let (done_tx, done_rx) = mpsc::channel();
+    let mut fdplane = FdPlane::builder()
+        .size((40, 5))
+        .on_done(move |result| done_tx.send(result.is_ok()).unwrap())
+        .build(&mut plane, &reader)?;
+    assert_eq![Size::new(40, 5), fdplane.plane().size()];
+
+    // a character split between writes, and an invalid byte.
+    writer.write_all(b"hello\ncaf\xc3")?;
+    writer.flush()?;
+    writer.write_all(b"\xa9 \xff!")?;
+    drop(writer);
+
+    // the reading ends at the end of file.
+    assert![done_rx.recv_timeout(Duration::from_secs(5)).unwrap()];
+    let contents = fdplane.plane_mut().contents()?;
+    assert![contents.contains("hello")];
+    assert![contents.contains("café \u{FFFD}!")];
+    plane.render()?;
+    Ok(())
+}
+
+#[test]
+fn subprocess_output() -> NotcursesResult<()> {
+    use std::{process::Command, sync::mpsc, time::Duration};
+
+    let _serial = serial();
+    let mut nc = Notcurses::headless((80, 24))?;
+    let mut plane = Plane::new(&mut nc)?;
+
+    let (exit_tx, exit_rx) = mpsc::channel();
+    let mut subprocess = Subprocess::builder()
+        .on_exit(move |status| exit_tx.send(status).unwrap())
+        .build(
+            &mut plane,
+            Command::new("sh").args(["-c", "echo out; echo err >&2; exit 3"]),
+        )?;
+    let status = exit_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq![Some(3), status.code()];
+
+    let contents = subprocess.plane_mut().contents()?;
+    assert![contents.contains("out")];
+    assert![contents.contains("err")];
+
+    let mut command = Command::new("true");
+    command.current_dir("/");
+    assert![Subprocess::builder().build(&mut plane, &command).is_err()];
+    Ok(())
+}